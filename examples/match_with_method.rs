@@ -1,5 +1,5 @@
 // Example demonstrating the method field in MatchResult
-use cricket_scoring::{Match, MatchResult, MatchType, Player, Team, WinMargin};
+use cricket_scoring::{Match, MatchResult, MatchType, Player, ResultMethod, Team, WinMargin};
 
 fn main() {
     // Create teams
@@ -9,6 +9,7 @@ fn main() {
             Player::new("Steve Smith".to_string()),
             Player::new("David Warner".to_string()),
         ],
+        ..Default::default()
     };
 
     let team2 = Team {
@@ -17,6 +18,7 @@ fn main() {
             Player::new("Joe Root".to_string()),
             Player::new("Ben Stokes".to_string()),
         ],
+        ..Default::default()
     };
 
     // Create match
@@ -33,7 +35,7 @@ fn main() {
     // Set result with Duckworth-Lewis method
     let result = MatchResult::Team1Won {
         margin: WinMargin::Runs(28),
-        method: Some("D/L".to_string()),
+        method: Some(ResultMethod::DuckworthLewis),
     };
     cricket_match.set_result(result);
 
@@ -54,6 +56,9 @@ fn main() {
                 WinMargin::Award => {
                     println!("Australia won by award{}", method_text);
                 }
+                WinMargin::Tiebreak(_) => {
+                    println!("Australia won the tiebreak{}", method_text);
+                }
             }
         }
         Some(MatchResult::Team2Won { margin, method }) => {
@@ -71,6 +76,9 @@ fn main() {
                 WinMargin::Award => {
                     println!("England won by award{}", method_text);
                 }
+                WinMargin::Tiebreak(_) => {
+                    println!("England won the tiebreak{}", method_text);
+                }
             }
         }
         Some(MatchResult::Tie { method }) => {
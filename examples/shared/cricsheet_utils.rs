@@ -3,7 +3,7 @@ use std::io::Read;
 
 use crate::cricsheet_lib::{Cricsheet, CricsheetInnings, Event};
 use cricket_scoring::scoring::innings::Innings;
-use cricket_scoring::scoring::r#match::{Match, MatchResult, WinMargin};
+use cricket_scoring::scoring::r#match::{Match, MatchResult, Tiebreak, WinMargin};
 
 pub fn compare_results(innings_data: &CricsheetInnings, innings: &Innings) {
     // print  cricsheet results
@@ -127,13 +127,17 @@ pub fn format_result(cricket_match: &Match) -> String {
                         eprintln!("Warning: Innings victory with wickets margin detected - correcting to {} run margin", run_margin);
                         format!("by an innings and {} runs", run_margin)
                     }
-                    WinMargin::Award => String::new(),
+                    WinMargin::Award | WinMargin::Tiebreak(_) => String::new(),
                 }
             } else {
                 match margin {
                     WinMargin::Runs(runs) => format!("by {} runs", runs),
                     WinMargin::Wickets(wickets) => format!("by {} wickets", wickets),
                     WinMargin::Award => String::new(),
+                    WinMargin::Tiebreak(Tiebreak::SuperOver) => String::from("the super over"),
+                    WinMargin::Tiebreak(Tiebreak::BoundaryCount) => {
+                        String::from("on boundary count")
+                    }
                 }
             };
             let method_text = match method {
@@ -160,13 +164,17 @@ pub fn format_result(cricket_match: &Match) -> String {
                         eprintln!("Warning: Innings victory with wickets margin detected - correcting to {} run margin", run_margin);
                         format!("by an innings and {} runs", run_margin)
                     }
-                    WinMargin::Award => String::new(),
+                    WinMargin::Award | WinMargin::Tiebreak(_) => String::new(),
                 }
             } else {
                 match margin {
                     WinMargin::Runs(runs) => format!("by {} runs", runs),
                     WinMargin::Wickets(wickets) => format!("by {} wickets", wickets),
                     WinMargin::Award => String::new(),
+                    WinMargin::Tiebreak(Tiebreak::SuperOver) => String::from("the super over"),
+                    WinMargin::Tiebreak(Tiebreak::BoundaryCount) => {
+                        String::from("on boundary count")
+                    }
                 }
             };
             let method_text = match method {
@@ -1,4 +1,9 @@
 mod option_string;
+mod over;
 mod string;
 pub use option_string::deserialize_to_option_string;
+pub use over::{
+    deserialize_delivery_to_over, deserialize_to_option_over, serialize_option_over,
+    serialize_over_as_delivery,
+};
 pub use string::deserialize_to_string;
@@ -18,6 +18,13 @@ impl de::Visitor<'_> for DeserializeOptionStringVisitor {
         Ok(None)
     }
 
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
     fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
     where
         E: de::Error,
@@ -25,6 +32,13 @@ impl de::Visitor<'_> for DeserializeOptionStringVisitor {
         Ok(Some(format!("{v}")))
     }
 
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Some(format!("{v}")))
+    }
+
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
         E: de::Error,
@@ -0,0 +1,60 @@
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+use crate::scoring::over::Over;
+
+/// Cricsheet writes overs as decimals in the "10.3" convention, where the digit
+/// after the point counts balls rather than tenths of an over
+fn decimal_to_over(value: f64) -> Over {
+    let over = value.trunc();
+    Over::new(over as i32, ((value - over) * 10.0).round() as i32)
+}
+
+/// The inverse of `decimal_to_over`, writing 10.3 for the third ball of the
+/// eleventh over
+fn over_to_decimal(over: &Over) -> f64 {
+    f64::from(over.over) + f64::from(over.ball) / 10.0
+}
+
+/// Reads a delivery number such as a powerplay's 5.6, where the ball counts
+/// from 1, as the overs bowled before that delivery, i.e. 5.5
+#[allow(clippy::module_name_repetitions)]
+pub fn deserialize_delivery_to_over<'de, D>(deserializer: D) -> Result<Over, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let delivery = decimal_to_over(f64::deserialize(deserializer)?);
+    if delivery.ball < 1 {
+        return Err(de::Error::custom(format!(
+            "delivery {delivery} must have a ball number from 1"
+        )));
+    }
+    Ok(Over::new(delivery.over, delivery.ball - 1))
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub fn deserialize_to_option_over<'de, D>(deserializer: D) -> Result<Option<Over>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<f64>::deserialize(deserializer)?.map(decimal_to_over))
+}
+
+/// The inverse of `deserialize_delivery_to_over`, writing 5.5 back out as 5.6
+#[allow(clippy::module_name_repetitions)]
+pub fn serialize_over_as_delivery<S>(over: &Over, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_f64(over_to_decimal(&Over::new(over.over, over.ball + 1)))
+}
+
+#[allow(clippy::module_name_repetitions, clippy::ref_option)]
+pub fn serialize_option_over<S>(over: &Option<Over>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match over {
+        Some(over) => serializer.serialize_some(&over_to_decimal(over)),
+        None => serializer.serialize_none(),
+    }
+}
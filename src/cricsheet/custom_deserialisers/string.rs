@@ -18,6 +18,13 @@ impl de::Visitor<'_> for DeserializeStringVisitor {
         Ok(format!("{v}"))
     }
 
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(format!("{v}"))
+    }
+
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
         E: de::Error,
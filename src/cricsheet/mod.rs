@@ -2,21 +2,42 @@
 
 // Module used to parse cricsheet files into native types
 
+use crate::error::CricsheetError;
 use crate::scoring::{
-    ball::{BallEvents, BallOutcome, Wicket as LibWicket},
-    innings::Innings,
+    ball::{BallEvents, BallOutcome, ReviewRecord, Wicket as LibWicket},
+    innings::{ForfeitBy, Innings},
+    over::Over as InningsOver,
     player::{Player, Team},
-    r#match::{Match, MatchResult, MatchType, WinMargin},
+    r#match::{Match, MatchResult, MatchType, ResultMethod, WinMargin},
 };
 use chrono::NaiveDate;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, fmt};
 
 mod custom_deserialisers;
-use custom_deserialisers::{deserialize_to_option_string, deserialize_to_string};
+use custom_deserialisers::{
+    deserialize_delivery_to_over, deserialize_to_option_over, deserialize_to_option_string,
+    deserialize_to_string, serialize_option_over, serialize_over_as_delivery,
+};
 
 pub mod utils;
 
+pub use crate::scoring::player::normalize_name;
+
+/// Parses a cricsheet match from its JSON representation
+///
+/// # Errors
+///
+/// Will return an error if the JSON is malformed or doesn't match the cricsheet format
+pub fn from_json(s: &str) -> Result<Cricsheet, CricsheetError> {
+    Ok(serde_json::from_str(s)?)
+}
+
+/// Whether two names are the same player once normalised, see `normalize_name`
+fn names_match(a: &str, b: &str) -> bool {
+    a == b || normalize_name(a) == normalize_name(b)
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Cricsheet {
     pub meta: CricsheetMeta,
@@ -26,8 +47,26 @@ pub struct Cricsheet {
 
 impl Cricsheet {
     pub fn create_game(&self) -> Match {
-        let team1 = self.info.clone().team(&self.info.teams[0]);
-        let team2 = self.info.clone().team(&self.info.teams[1]);
+        let mut team1 = self.info.clone().team(&self.info.teams[0]);
+        let mut team2 = self.info.clone().team(&self.info.teams[1]);
+
+        // Super-subs are kept in the squad but don't bat unless substituted in
+        for (team_name, sub) in self.info.supersubs.iter().flatten() {
+            let team = if team_name == &team1.name {
+                &mut team1
+            } else if team_name == &team2.name {
+                &mut team2
+            } else {
+                continue;
+            };
+            match team.get_player(sub) {
+                Some(player) => player.super_sub = true,
+                None => team.players.push(Player {
+                    super_sub: true,
+                    ..Player::new(sub.clone())
+                }),
+            }
+        }
 
         let match_type = match self.info.match_type.to_lowercase().as_str() {
             "test" => MatchType::Test,
@@ -36,24 +75,48 @@ impl Cricsheet {
             _ => MatchType::Other(self.info.match_type.clone()),
         };
 
-        let mut cricket_match = Match::new(
+        // Cricsheet doesn't say which side is at home, so `home_team` stays `None`
+        let mut builder = Match::builder(
             String::from("1"),
             format!("{} vs {}", self.info.teams[0], self.info.teams[1]),
             match_type,
             team1,
             team2,
-        );
+        )
+        .dates(
+            self.info
+                .dates
+                .iter()
+                .map(|date| date.to_string())
+                .collect(),
+        )
+        .season(self.info.season.clone())
+        .balls_per_over(self.info.balls_per_over)
+        .player_of_match(self.info.player_of_match.clone().unwrap_or_default())
+        .gender(self.info.gender.clone())
+        .team_type(self.info.team_type.clone());
 
         if let Some(venue) = &self.info.venue {
-            cricket_match = cricket_match.with_venue(venue.clone());
+            builder = builder.venue(venue.clone());
         }
-
-        if let Some(first_date) = self.info.dates.first() {
-            cricket_match = cricket_match.with_date(first_date.to_string());
+        if let Some(match_number) = self.info.match_type_number {
+            builder = builder.match_number(match_number);
         }
-
         if let Some(event) = &self.info.event {
-            cricket_match.with_event(event.name.clone());
+            builder = builder.event(event.name.clone());
+        }
+        if let Some(overs) = self.info.overs {
+            builder = builder.max_overs(overs);
+        }
+        let mut cricket_match = builder.build();
+
+        // An awarded match may have no play to calculate a result from
+        if self.info.outcome.is_awarded() {
+            let match_result = self
+                .info
+                .outcome
+                .create_match_result(&cricket_match.team1.name, &cricket_match.team2.name);
+            cricket_match.set_result(match_result);
         }
 
         cricket_match
@@ -104,21 +167,31 @@ impl CricsheetInfo {
                 .iter()
                 .map(|x| Player::new(x.clone()))
                 .collect::<Vec<Player>>(),
+            ..Default::default()
         }
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct CricsheetInnings {
     pub team: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub overs: Option<Vec<Over>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub absent_hurt: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub penalty_runs: Option<PenaltyRuns>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub declared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forfeited: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub powerplays: Option<Vec<Powerplay>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub miscounted_overs: Option<HashMap<String, MiscountedOver>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<Target>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub super_over: Option<bool>,
 }
 
@@ -138,10 +211,12 @@ impl CricsheetInnings {
         };
 
         let mut innings = Innings::new(batting_team.clone(), bowling_team.clone());
+        cricket_match.configure_innings(&mut innings);
+        innings.target = self.target.as_ref().and_then(|target| target.runs);
 
         // check for penalty runs
-        if self.penalty_runs.is_some() {
-            innings.score.runs = self.penalty_runs.as_ref().unwrap().pre.unwrap_or_default();
+        if let Some(penalty_runs) = &self.penalty_runs {
+            innings.add_innings_penalty(penalty_runs.pre.unwrap_or_default());
         }
 
         // iterate through overs and balls
@@ -151,21 +226,21 @@ impl CricsheetInnings {
                 let striker = batting_team
                     .players
                     .iter()
-                    .find(|p| p.name == ball.batter)
+                    .find(|p| names_match(&p.name, &ball.batter))
                     .expect("Batter from delivery not found in batting team")
                     .clone();
 
                 let non_striker = batting_team
                     .players
                     .iter()
-                    .find(|p| p.name == ball.non_striker)
+                    .find(|p| names_match(&p.name, &ball.non_striker))
                     .expect("Non-striker from delivery not found in batting team")
                     .clone();
 
                 let bowler = bowling_team
                     .players
                     .iter()
-                    .find(|p| p.name == ball.bowler)
+                    .find(|p| names_match(&p.name, &ball.bowler))
                     .unwrap()
                     .clone();
 
@@ -175,10 +250,29 @@ impl CricsheetInnings {
             innings.over();
         }
         innings.finished = true;
+        innings.declared = self.declared.unwrap_or(false);
+        innings.forfeited = self.forfeited.unwrap_or(false);
+        // Cricsheet doesn't say who forfeited. A match awarded to the side whose
+        // innings was forfeited can only mean the fielding side refused to play.
+        let awarded = matches!(
+            cricket_match.result,
+            Some(
+                MatchResult::Team1Won {
+                    margin: WinMargin::Award,
+                    ..
+                } | MatchResult::Team2Won {
+                    margin: WinMargin::Award,
+                    ..
+                }
+            )
+        );
+        if innings.forfeited && awarded && cricket_match.winner() == Some(&batting_team.name) {
+            innings.forfeited_by = ForfeitBy::Bowling;
+        }
 
         // check for penalty runs
-        if self.penalty_runs.is_some() {
-            innings.score.runs += self.penalty_runs.as_ref().unwrap().post.unwrap_or_default();
+        if let Some(penalty_runs) = &self.penalty_runs {
+            innings.add_innings_penalty(penalty_runs.post.unwrap_or_default());
         }
         cricket_match.add_innings(innings.clone());
     }
@@ -200,10 +294,11 @@ impl CricsheetInnings {
         };
 
         let mut innings = Innings::new(batting_team.clone(), bowling_team.clone());
+        innings.target = self.target.as_ref().and_then(|target| target.runs);
 
         // check for penalty runs
-        if self.penalty_runs.is_some() {
-            innings.score.runs = self.penalty_runs.as_ref().unwrap().pre.unwrap_or_default();
+        if let Some(penalty_runs) = &self.penalty_runs {
+            innings.add_innings_penalty(penalty_runs.pre.unwrap_or_default());
         }
 
         // iterate through overs and balls
@@ -213,21 +308,21 @@ impl CricsheetInnings {
                 let striker = batting_team
                     .players
                     .iter()
-                    .find(|p| p.name == ball.batter)
+                    .find(|p| names_match(&p.name, &ball.batter))
                     .expect("Batter from delivery not found in batting team")
                     .clone();
 
                 let non_striker = batting_team
                     .players
                     .iter()
-                    .find(|p| p.name == ball.non_striker)
+                    .find(|p| names_match(&p.name, &ball.non_striker))
                     .expect("Non-striker from delivery not found in batting team")
                     .clone();
 
                 let bowler = bowling_team
                     .players
                     .iter()
-                    .find(|p| p.name == ball.bowler)
+                    .find(|p| names_match(&p.name, &ball.bowler))
                     .unwrap()
                     .clone();
 
@@ -238,10 +333,12 @@ impl CricsheetInnings {
             innings.over();
         }
         innings.finished = true;
+        innings.declared = self.declared.unwrap_or(false);
+        innings.forfeited = self.forfeited.unwrap_or(false);
 
         // check for penalty runs
-        if self.penalty_runs.is_some() {
-            innings.score.runs += self.penalty_runs.as_ref().unwrap().post.unwrap_or_default();
+        if let Some(penalty_runs) = &self.penalty_runs {
+            innings.add_innings_penalty(penalty_runs.post.unwrap_or_default());
         }
 
         // Update the last state with the final innings (with finished flag and post-penalty runs)
@@ -253,48 +350,125 @@ impl CricsheetInnings {
     }
 }
 
-#[derive(Deserialize, Debug)]
+impl From<&Innings> for CricsheetInnings {
+    /// Rebuilds the cricsheet overs and deliveries from an innings' ball records.
+    /// Dead balls aren't recorded by cricsheet so are left out. Any penalty runs
+    /// not awarded on a delivery are written as `post` penalty runs, as an innings
+    /// doesn't keep whether they were awarded before or after it.
+    fn from(innings: &Innings) -> CricsheetInnings {
+        let mut overs: Vec<Over> = Vec::new();
+        for record in innings.deliveries() {
+            if record.outcome.dead_ball {
+                continue;
+            }
+            let delivery = Delivery::from(&record.outcome);
+            match overs.last_mut() {
+                Some(over) if over.over == record.over => over.deliveries.push(delivery),
+                _ => overs.push(Over {
+                    over: record.over,
+                    deliveries: vec![delivery],
+                }),
+            }
+        }
+
+        let innings_penalties = innings.score.innings_penalties;
+        CricsheetInnings {
+            team: innings.batting_team.name.clone(),
+            overs: (!overs.is_empty()).then_some(overs),
+            absent_hurt: None,
+            penalty_runs: (innings_penalties > 0).then_some(PenaltyRuns {
+                pre: None,
+                post: Some(innings_penalties),
+            }),
+            declared: innings.declared.then_some(true),
+            forfeited: innings.forfeited.then_some(true),
+            powerplays: None,
+            miscounted_overs: None,
+            target: innings.target.map(|runs| Target {
+                overs: None,
+                runs: Some(runs),
+            }),
+            super_over: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
 pub struct PenaltyRuns {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pre: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub post: Option<i32>,
 }
 
-#[derive(Deserialize, Debug)]
+/// A powerplay, running from the `from` delivery to the `to` delivery inclusive.
+/// Each is held as the overs bowled before that delivery, so cricsheet's 0.1 to
+/// 5.6 is read as 0.0 to 5.5. Powerplays are kept for reference only: phase
+/// stats use `Phase::of` rather than these ranges.
+#[derive(Deserialize, Serialize, Debug)]
 pub struct Powerplay {
-    pub from: f32,
-    pub to: f32,
+    #[serde(
+        deserialize_with = "deserialize_delivery_to_over",
+        serialize_with = "serialize_over_as_delivery"
+    )]
+    pub from: InningsOver,
+    #[serde(
+        deserialize_with = "deserialize_delivery_to_over",
+        serialize_with = "serialize_over_as_delivery"
+    )]
+    pub to: InningsOver,
     #[serde(rename = "type")]
     pub kind: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct MiscountedOver {
     #[serde(deserialize_with = "deserialize_to_string")]
     pub balls: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub umpire: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct Target {
-    pub overs: Option<f32>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_to_option_over",
+        serialize_with = "serialize_option_over",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub overs: Option<InningsOver>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub runs: Option<i32>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl Target {
+    /// The legal balls available to reach the target
+    #[must_use]
+    pub fn balls(&self, balls_per_over: i32) -> Option<i32> {
+        self.overs.map(|overs| overs.to_balls(balls_per_over))
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Over {
     pub over: i32,
     pub deliveries: Vec<Delivery>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Delivery {
     pub batter: String,
     pub bowler: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub extras: Option<Extras>,
     pub non_striker: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replacements: Option<Replacement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub review: Option<Review>,
     pub runs: Runs,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub wickets: Option<Vec<Wicket>>,
 }
 
@@ -335,6 +509,13 @@ impl Delivery {
                     .map(|x| LibWicket {
                         player_out: x.player_out,
                         kind: x.kind,
+                        fielders: x
+                            .fielders
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter_map(|fielder| fielder.name)
+                            .collect(),
+                        ..Default::default()
                     })
                     .collect(),
             ));
@@ -346,74 +527,195 @@ impl Delivery {
             ball_events.push(BallEvents::Six);
         }
 
-        let ball_outcome =
+        let mut ball_outcome =
             BallOutcome::new(self.runs.batter, ball_events, striker, non_striker, bowler);
+        ball_outcome.review = self.review.as_ref().map(|review| ReviewRecord {
+            by: review.by.clone(),
+            batter: review.batter.clone(),
+            umpire: review.umpire.clone(),
+            upheld: review.decision == "upheld",
+            umpires_call: review.umpires_call.unwrap_or(false),
+        });
         ball_outcome.validate().unwrap();
         ball_outcome
     }
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl From<&BallOutcome> for Delivery {
+    /// The inverse of `Delivery::parse`. As cricsheet records them, everything
+    /// run off a wide counts as wides, and overthrows go to the batter unless
+    /// they were added to byes or leg byes.
+    fn from(outcome: &BallOutcome) -> Delivery {
+        let overthrows = outcome.overthrows.unwrap_or(0);
+        let extras = if let Some(wide) = outcome.wide {
+            Extras {
+                byes: None,
+                legbyes: None,
+                noballs: outcome.no_ball,
+                penalty: outcome.penalty,
+                wides: Some(
+                    wide + outcome.runs
+                        + outcome.byes.unwrap_or(0)
+                        + outcome.leg_byes.unwrap_or(0)
+                        + overthrows,
+                ),
+            }
+        } else {
+            // As in scoring, overthrows on byes and leg byes together go to the byes
+            let leg_bye_overthrows = if outcome.byes.is_some() {
+                0
+            } else {
+                overthrows
+            };
+            Extras {
+                byes: outcome.byes.map(|byes| byes + overthrows),
+                legbyes: outcome
+                    .leg_byes
+                    .map(|leg_byes| leg_byes + leg_bye_overthrows),
+                noballs: outcome.no_ball,
+                penalty: outcome.penalty,
+                wides: None,
+            }
+        };
+        let batter = if outcome.wide.is_some() {
+            0
+        } else if outcome.byes.is_some() || outcome.leg_byes.is_some() {
+            outcome.runs
+        } else {
+            outcome.runs + overthrows
+        };
+        let total = outcome.total_runs();
+        let has_extras = extras.byes.is_some()
+            || extras.legbyes.is_some()
+            || extras.noballs.is_some()
+            || extras.penalty.is_some()
+            || extras.wides.is_some();
+
+        Delivery {
+            batter: outcome.on_strike.name.clone(),
+            bowler: outcome.bowler.name.clone(),
+            extras: has_extras.then_some(extras),
+            non_striker: outcome.off_strike.name.clone(),
+            replacements: None,
+            review: outcome.review.as_ref().map(|review| Review {
+                batter: review.batter.clone(),
+                by: review.by.clone(),
+                decision: if review.upheld {
+                    "upheld"
+                } else {
+                    "struck down"
+                }
+                .to_string(),
+                umpire: review.umpire.clone(),
+                umpires_call: review.umpires_call.then_some(true),
+            }),
+            runs: Runs {
+                batter,
+                extras: total - batter,
+                non_boundary: ((batter == 4 && !outcome.four)
+                    || (batter == 6 && !outcome.six)
+                    || outcome.overthrows_to_boundary)
+                    .then_some(true),
+                total,
+            },
+            wickets: outcome.wicket.as_ref().map(|wickets| {
+                wickets
+                    .iter()
+                    .map(|wicket| Wicket {
+                        fielders: (!wicket.fielders.is_empty()).then(|| {
+                            wicket
+                                .fielders
+                                .iter()
+                                .map(|name| Fielder {
+                                    name: Some(name.clone()),
+                                    substitute: None,
+                                })
+                                .collect()
+                        }),
+                        kind: wicket.kind.clone(),
+                        player_out: wicket.player_out.clone(),
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Extras {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub byes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub legbyes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub noballs: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub penalty: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub wides: Option<i32>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Replacement {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub role: Option<Vec<ReplacementRole>>,
-    #[serde(rename = "match")]
+    #[serde(rename = "match", skip_serializing_if = "Option::is_none")]
     pub game: Option<Vec<ReplacementMatch>>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ReplacementRole {
     #[serde(rename = "in")]
     pub player_in: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub out: Option<String>,
     pub reason: String,
     pub role: String,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ReplacementMatch {
     #[serde(rename = "in")]
     pub player_in: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub out: Option<String>,
     pub reason: String,
     pub team: String,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Review {
     pub batter: String,
     pub by: String,
     pub decision: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub umpire: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub umpires_call: Option<bool>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Runs {
     pub batter: i32,
     pub extras: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub non_boundary: Option<bool>,
     pub total: i32,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Wicket {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fielders: Option<Vec<Fielder>>,
     pub kind: String,
     pub player_out: String,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Fielder {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub substitute: Option<bool>,
 }
 
@@ -461,17 +763,17 @@ pub struct Method {
 
 impl fmt::Display for Method {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.runs.is_some() {
+        if let Some(runs) = self.runs {
             if self.innings.is_some() {
-                return write!(f, "Won by an innings and {} runs", self.runs.unwrap());
+                return write!(f, "Won by an innings and {runs} runs");
             };
-            return write!(f, "Won by {} runs", self.runs.unwrap());
+            return write!(f, "Won by {runs} runs");
         }
-        if self.wickets.is_some() {
+        if let Some(wickets) = self.wickets {
             if self.innings.is_some() {
-                return write!(f, "Won by an innings and {} wickets", self.wickets.unwrap());
+                return write!(f, "Won by an innings and {wickets} wickets");
             };
-            return write!(f, "Won by {} wickets", self.wickets.unwrap());
+            return write!(f, "Won by {wickets} wickets");
         }
         panic!("No winning information");
     }
@@ -488,6 +790,26 @@ pub struct Outcome {
 }
 
 impl Outcome {
+    /// Whether the match was awarded to the winner (e.g. on a forfeit) rather than
+    /// won by a margin of runs or wickets
+    #[must_use]
+    pub fn is_awarded(&self) -> bool {
+        self.winner.is_some()
+            && self
+                .by
+                .as_ref()
+                .is_none_or(|by| by.runs.is_none() && by.wickets.is_none())
+    }
+
+    /// The typed method the result was reached by, if there was one
+    #[must_use]
+    pub fn result_method(&self) -> Option<ResultMethod> {
+        self.method.as_deref().map(|method| {
+            let Ok(method) = method.parse();
+            method
+        })
+    }
+
     pub fn create_match_result(&self, team1_name: &str, team2_name: &str) -> MatchResult {
         // Handle special cases first
         if self.result == Some(String::from("draw")) {
@@ -495,7 +817,7 @@ impl Outcome {
         }
 
         if self.result == Some(String::from("tie")) {
-            let method = self.method.as_ref().map(|m| m.clone());
+            let method = self.result_method();
             return MatchResult::Tie { method };
         }
 
@@ -519,7 +841,7 @@ impl Outcome {
                 WinMargin::Award
             };
 
-            let method = self.method.as_ref().map(|m| m.clone());
+            let method = self.result_method();
             if winner == team1_name {
                 MatchResult::Team1Won { margin, method }
             } else if winner == team2_name {
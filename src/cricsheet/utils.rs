@@ -3,7 +3,7 @@ use std::io::Read;
 
 use super::{Cricsheet, CricsheetInnings, Event};
 use crate::scoring::innings::Innings;
-use crate::scoring::r#match::{Match, MatchResult, WinMargin};
+use crate::scoring::r#match::{Match, MatchResult, Tiebreak, WinMargin};
 
 pub fn compare_results(innings_data: &CricsheetInnings, innings: &Innings) {
     // print  cricsheet results
@@ -14,19 +14,9 @@ pub fn compare_results(innings_data: &CricsheetInnings, innings: &Innings) {
         .iter()
         .map(|x| x.deliveries.iter().map(|z| z.runs.total).sum::<i32>())
         .sum::<i32>();
-    if innings_data.penalty_runs.is_some() {
-        cricsheet_runs += innings_data
-            .penalty_runs
-            .as_ref()
-            .unwrap()
-            .pre
-            .unwrap_or_default()
-            + innings_data
-                .penalty_runs
-                .as_ref()
-                .unwrap()
-                .post
-                .unwrap_or_default();
+    if let Some(penalty_runs) = &innings_data.penalty_runs {
+        cricsheet_runs +=
+            penalty_runs.pre.unwrap_or_default() + penalty_runs.post.unwrap_or_default();
     }
     let cricsheet_wickets: usize = innings_data
         .overs
@@ -74,6 +64,14 @@ pub fn compare_results(innings_data: &CricsheetInnings, innings: &Innings) {
         println!("{:?}", innings_data.penalty_runs);
         // println!("{innings}");
     }
+    if let Some(excess) = innings.balls_over_limit() {
+        println!(
+            "{} -> {} legal balls more than the {} over limit",
+            innings.batting_team.name,
+            excess,
+            innings.max_overs.unwrap_or_default()
+        );
+    }
 }
 
 pub fn get_cricsheet_game(directory_entry: &DirEntry) -> Option<Cricsheet> {
@@ -97,12 +95,7 @@ pub fn format_cricsheet_result(cricsheet: &Cricsheet) -> String {
     };
     format!(
         "{} {by} {}",
-        cricsheet
-            .info
-            .outcome
-            .winner
-            .clone()
-            .unwrap_or(String::new()),
+        cricsheet.info.outcome.winner.clone().unwrap_or_default(),
         cricsheet.info.outcome.method.clone().unwrap_or_default()
     )
 }
@@ -127,13 +120,17 @@ pub fn format_result(cricket_match: &Match) -> String {
                         eprintln!("Warning: Innings victory with wickets margin detected - correcting to {} run margin", run_margin);
                         format!("by an innings and {} runs", run_margin)
                     }
-                    WinMargin::Award => String::new(),
+                    WinMargin::Award | WinMargin::Tiebreak(_) => String::new(),
                 }
             } else {
                 match margin {
                     WinMargin::Runs(runs) => format!("by {} runs", runs),
                     WinMargin::Wickets(wickets) => format!("by {} wickets", wickets),
                     WinMargin::Award => String::new(),
+                    WinMargin::Tiebreak(Tiebreak::SuperOver) => String::from("the super over"),
+                    WinMargin::Tiebreak(Tiebreak::BoundaryCount) => {
+                        String::from("on boundary count")
+                    }
                 }
             };
             let method_text = match method {
@@ -160,13 +157,17 @@ pub fn format_result(cricket_match: &Match) -> String {
                         eprintln!("Warning: Innings victory with wickets margin detected - correcting to {} run margin", run_margin);
                         format!("by an innings and {} runs", run_margin)
                     }
-                    WinMargin::Award => String::new(),
+                    WinMargin::Award | WinMargin::Tiebreak(_) => String::new(),
                 }
             } else {
                 match margin {
                     WinMargin::Runs(runs) => format!("by {} runs", runs),
                     WinMargin::Wickets(wickets) => format!("by {} wickets", wickets),
                     WinMargin::Award => String::new(),
+                    WinMargin::Tiebreak(Tiebreak::SuperOver) => String::from("the super over"),
+                    WinMargin::Tiebreak(Tiebreak::BoundaryCount) => {
+                        String::from("on boundary count")
+                    }
                 }
             };
             let method_text = match method {
@@ -1,6 +1,8 @@
 use thiserror::Error;
 
-#[derive(Error, Debug, Clone)]
+use crate::scoring::MatchResult;
+
+#[derive(Error, Debug, Clone, PartialEq)]
 pub enum BallString {
     #[error("Ball string can't be empty")]
     EmptyBallString,
@@ -18,6 +20,52 @@ pub enum BallString {
 pub enum BallOutcomeValidation {
     #[error("Incompatible double outcomes {0} and {1} given.")]
     DoubleOutcome(String, String),
+    #[error("Could not deserialize ball outcome: {0}")]
+    Deserialize(String),
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum MatchCreationError {
+    #[error("A match can't be played between {0} and itself")]
+    DuplicateTeamNames(String),
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum InningsError {
+    #[error("Batter index {index} is out of range for a team of {players} players")]
+    StrikeIndexOutOfRange { index: usize, players: usize },
+    #[error("The same batter can't be at both ends")]
+    SameBatterAtBothEnds,
+    #[error("The batting side is all out")]
+    AllOut,
+    #[error("Ball {index} ({ball}) comes after the batting side is all out")]
+    BallAfterAllOut { index: usize, ball: String },
+    #[error("The bowling team has no players to bowl")]
+    NoBowlers,
+    #[error(transparent)]
+    InvalidBall(#[from] BallString),
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("Stored result {stored:?} doesn't match the calculated result {calculated:?}")]
+pub struct ResultMismatch {
+    pub stored: Option<MatchResult>,
+    pub calculated: Option<MatchResult>,
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ParseOversError {
+    #[error("Overs must be written as overs.balls, got {0}")]
+    InvalidFormat(String),
+    #[error("Ball {ball} is out of range for {balls_per_over} ball overs")]
+    BallOutOfRange { ball: i32, balls_per_over: i32 },
+}
+
+#[cfg(feature = "cricsheet")]
+#[derive(Error, Debug)]
+pub enum CricsheetError {
+    #[error("Could not parse cricsheet JSON: {0}")]
+    Json(#[from] serde_json::Error),
 }
 
 #[cfg(test)]
@@ -54,6 +102,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_match_creation_duplicate_team_names_error() {
+        let error = MatchCreationError::DuplicateTeamNames("Australia".to_string());
+        assert_eq!(
+            error.to_string(),
+            "A match can't be played between Australia and itself"
+        );
+    }
+
+    #[test]
+    fn test_innings_strike_index_error() {
+        let error = InningsError::StrikeIndexOutOfRange {
+            index: 11,
+            players: 11,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Batter index 11 is out of range for a team of 11 players"
+        );
+        assert_eq!(
+            InningsError::AllOut.to_string(),
+            "The batting side is all out"
+        );
+    }
+
+    #[test]
+    fn test_parse_overs_errors() {
+        let error = ParseOversError::InvalidFormat("ten".to_string());
+        assert_eq!(
+            error.to_string(),
+            "Overs must be written as overs.balls, got ten"
+        );
+        let error = ParseOversError::BallOutOfRange {
+            ball: 7,
+            balls_per_over: 6,
+        };
+        assert_eq!(error.to_string(), "Ball 7 is out of range for 6 ball overs");
+    }
+
     #[test]
     fn test_ball_string_clone() {
         let original = BallString::EmptyBallString;
@@ -97,7 +184,7 @@ mod tests {
 
     #[test]
     fn test_different_ball_string_errors() {
-        let errors = vec![
+        let errors = [
             BallString::EmptyBallString,
             BallString::InvalidBallStringCharacter('Y'),
             BallString::InvalidByeCharacter,
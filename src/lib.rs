@@ -1,11 +1,14 @@
 pub mod error;
 pub mod scoring;
+pub mod stats;
 
 #[cfg(feature = "cricsheet")]
 pub mod cricsheet;
 
 // Re-export commonly used types at the crate root for convenience
 pub use scoring::{
-    BallEvents, BallOutcome, CurrentScore, Innings, Match, MatchResult, MatchStatus, MatchType,
-    Player, Team, Wicket, WinMargin,
+    Ahead, BallEvents, BallNumber, BallOutcome, BallRecord, CurrentScore, Decision, Dismissal,
+    DismissalKind, ForfeitBy, FowEntry, Innings, Match, MatchBuilder, MatchResult, MatchSituation,
+    MatchStatus, MatchType, Matchup, Over, OverSummary, Partnership, Phase, Player, ResultMethod,
+    RunSources, Team, Tiebreak, Wicket, WinMargin,
 };
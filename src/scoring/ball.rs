@@ -1,15 +1,114 @@
 use super::player::Player;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::{convert::Infallible, str::FromStr};
 
 use crate::error::BallOutcomeValidation;
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Wicket {
     pub player_out: String,
     pub kind: String,
+    /// For run outs, the number of runs completed before the wicket fell.
+    /// Each completed run means the batters crossed, which decides which end
+    /// the incoming batter takes.
+    #[serde(default)]
+    pub completed_runs: Option<i32>,
+    /// The fielders involved, e.g. the catcher or those who effected a run out
+    #[serde(default)]
+    pub fielders: Vec<String>,
 }
 
-#[derive(PartialEq, Debug, Serialize, Deserialize)]
+impl Wicket {
+    /// The kind of dismissal, parsed from `kind`
+    #[must_use]
+    pub fn dismissal_kind(&self) -> DismissalKind {
+        let Ok(kind) = self.kind.parse();
+        kind
+    }
+
+    /// Whether the dismissal counts towards the bowler's wickets
+    #[must_use]
+    pub fn credited_to_bowler(&self) -> bool {
+        self.dismissal_kind().credited_to_bowler()
+    }
+}
+
+/// The ways a batter's innings can end, as named by cricsheet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DismissalKind {
+    Bowled,
+    Caught,
+    CaughtAndBowled,
+    Lbw,
+    Stumped,
+    RunOut,
+    HitWicket,
+    ObstructingField,
+    HandledTheBall,
+    HitBallTwice,
+    TimedOut,
+    RetiredOut,
+    RetiredHurt,
+    RetiredNotOut,
+    /// Any other kind, with its description
+    Other(String),
+}
+
+impl DismissalKind {
+    /// Whether the batting side loses a wicket. Retiring hurt or not out doesn't
+    /// cost a wicket, and neither does any other kind of retirement.
+    #[must_use]
+    pub fn is_wicket(&self) -> bool {
+        match self {
+            DismissalKind::RetiredHurt | DismissalKind::RetiredNotOut => false,
+            DismissalKind::Other(kind) => !kind.to_lowercase().contains("retired"),
+            _ => true,
+        }
+    }
+
+    /// Whether the dismissal counts towards the bowler's wickets
+    #[must_use]
+    pub fn credited_to_bowler(&self) -> bool {
+        match self {
+            DismissalKind::RunOut
+            | DismissalKind::ObstructingField
+            | DismissalKind::HandledTheBall
+            | DismissalKind::HitBallTwice
+            | DismissalKind::TimedOut
+            | DismissalKind::RetiredOut => false,
+            _ => self.is_wicket(),
+        }
+    }
+}
+
+impl FromStr for DismissalKind {
+    type Err = Infallible;
+
+    /// Parses a dismissal case-insensitively. Unknown kinds become
+    /// `DismissalKind::Other`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind = match s.trim().to_lowercase().as_str() {
+            "bowled" => DismissalKind::Bowled,
+            "caught" => DismissalKind::Caught,
+            "caught and bowled" => DismissalKind::CaughtAndBowled,
+            "lbw" => DismissalKind::Lbw,
+            "stumped" => DismissalKind::Stumped,
+            "run out" => DismissalKind::RunOut,
+            "hit wicket" => DismissalKind::HitWicket,
+            "obstructing the field" | "obstructing field" => DismissalKind::ObstructingField,
+            "handled the ball" => DismissalKind::HandledTheBall,
+            "hit the ball twice" => DismissalKind::HitBallTwice,
+            "timed out" => DismissalKind::TimedOut,
+            "retired out" => DismissalKind::RetiredOut,
+            "retired hurt" => DismissalKind::RetiredHurt,
+            "retired not out" => DismissalKind::RetiredNotOut,
+            _ => DismissalKind::Other(s.to_string()),
+        };
+        Ok(kind)
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub enum BallEvents {
     Bye(i32),
     LegBye(i32),
@@ -17,11 +116,19 @@ pub enum BallEvents {
     Wicket(Vec<Wicket>),
     Wide(i32),
     Penalty(i32),
+    /// Extra runs from an overthrow, on top of the runs completed, that the
+    /// batters ran
+    Overthrows(i32),
+    /// Overthrows that reached the boundary, on top of the runs completed. Off the
+    /// bat they count as a four for the batter.
+    BoundaryOverthrows(i32),
+    /// The umpire called the delivery dead, so nothing from it counts
+    DeadBall,
     Four,
     Six,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct BallOutcome {
     pub runs: i32,
     pub wicket: Option<Vec<Wicket>>,
@@ -36,6 +143,61 @@ pub struct BallOutcome {
     pub off_strike: Player,
     pub bowler: Player,
     pub penalty: Option<i32>,
+    /// Runs from overthrows. These go to the batter if they hit the ball, or are
+    /// added to the byes, leg byes or wides otherwise.
+    #[serde(default)]
+    pub overthrows: Option<i32>,
+    /// The overthrows reached the boundary, so weren't run
+    #[serde(default)]
+    pub overthrows_to_boundary: bool,
+    /// The delivery was called dead. It's kept in the record but doesn't add runs,
+    /// count as a ball faced or bowled, or change any other stats.
+    #[serde(default)]
+    pub dead_ball: bool,
+    /// Whether the batter was in control of their shot, if known. Cricsheet doesn't
+    /// record this.
+    #[serde(default)]
+    pub controlled: Option<bool>,
+    /// A review (DRS) of the umpire's decision on this delivery, if there was one
+    #[serde(default)]
+    pub review: Option<ReviewRecord>,
+}
+
+/// A review (DRS) of an on-field umpire's decision
+#[derive(Default, Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    /// The team that asked for the review
+    pub by: String,
+    /// The batter the decision concerned
+    pub batter: String,
+    /// The umpire whose decision was reviewed
+    pub umpire: Option<String>,
+    /// Whether the review succeeded and the decision was overturned, as opposed
+    /// to being struck down
+    pub upheld: bool,
+    /// The decision stood on umpire's call
+    pub umpires_call: bool,
+}
+
+/// A delivery as it was scored, along with where it fell in the innings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BallRecord {
+    /// The over the delivery was bowled in (0-indexed)
+    pub over: i32,
+    /// Legal balls already bowled in the over when this delivery was bowled
+    pub ball: i32,
+    pub outcome: BallOutcome,
+    /// When the delivery was bowled, if it was recorded
+    #[cfg(feature = "chrono")]
+    #[serde(default)]
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// Commentary on the delivery, for display
+    #[serde(default)]
+    pub commentary: Option<String>,
+    /// The end the delivery was bowled from, e.g. "Pavilion End", if it was
+    /// recorded. Cricsheet doesn't carry this.
+    #[serde(default)]
+    pub end: Option<String>,
 }
 
 impl BallOutcome {
@@ -64,11 +226,120 @@ impl BallOutcome {
                 BallEvents::Four => outcome.four = true,
                 BallEvents::Six => outcome.six = true,
                 BallEvents::Penalty(x) => outcome.penalty = Some(x),
+                BallEvents::Overthrows(x) => outcome.overthrows = Some(x),
+                BallEvents::BoundaryOverthrows(x) => {
+                    outcome.overthrows = Some(x);
+                    outcome.overthrows_to_boundary = true;
+                }
+                BallEvents::DeadBall => outcome.dead_ball = true,
             }
         }
         outcome
     }
 
+    /// Runs credited to the striker, including any overthrows off their shot
+    #[must_use]
+    pub fn runs_off_bat(&self) -> i32 {
+        if self.wide.is_some() || self.byes.is_some() || self.leg_byes.is_some() {
+            self.runs
+        } else {
+            self.runs + self.overthrows.unwrap_or(0)
+        }
+    }
+
+    /// Whether this records a batter timed out, which happens between deliveries
+    /// so no ball is bowled or faced
+    #[must_use]
+    pub fn is_timed_out(&self) -> bool {
+        self.wicket.as_ref().is_some_and(|wickets| {
+            !wickets.is_empty()
+                && wickets
+                    .iter()
+                    .all(|wicket| wicket.dismissal_kind() == DismissalKind::TimedOut)
+        })
+    }
+
+    /// Whether the delivery counts towards the over, i.e. it's not a wide or no
+    /// ball and a ball was actually bowled
+    #[must_use]
+    pub fn is_legal(&self) -> bool {
+        self.wide.is_none() && self.no_ball.is_none() && !self.dead_ball && !self.is_timed_out()
+    }
+
+    /// Whether the striker faced the delivery, which they do unless it's a wide
+    /// or no ball was bowled
+    #[must_use]
+    pub fn faced_by_striker(&self) -> bool {
+        self.wide.is_none() && !self.dead_ball && !self.is_timed_out()
+    }
+
+    /// Runs added to the total by this delivery, including extras
+    #[must_use]
+    pub fn total_runs(&self) -> i32 {
+        self.runs
+            + self.overthrows.unwrap_or(0)
+            + self.wide.unwrap_or(0)
+            + self.no_ball.unwrap_or(0)
+            + self.byes.unwrap_or(0)
+            + self.leg_byes.unwrap_or(0)
+            + self.penalty.unwrap_or(0)
+    }
+
+    /// Runs charged to the bowler, i.e. everything but byes, leg byes and penalties.
+    /// Anything run off a wide counts as wides, so is charged to the bowler.
+    #[must_use]
+    pub fn bowler_runs(&self) -> i32 {
+        if self.wide.is_some() {
+            return self.total_runs() - self.penalty.unwrap_or(0);
+        }
+        let extra_overthrows = if self.byes.is_some() || self.leg_byes.is_some() {
+            self.overthrows.unwrap_or(0)
+        } else {
+            0
+        };
+        self.total_runs()
+            - self.byes.unwrap_or(0)
+            - self.leg_byes.unwrap_or(0)
+            - self.penalty.unwrap_or(0)
+            - extra_overthrows
+    }
+
+    /// Runs the batters physically ran on this delivery, which decides whether
+    /// they changed ends. Boundaries aren't run, and neither is the one run
+    /// penalty for a wide or no ball. Overthrows are run unless they're marked as
+    /// reaching the boundary, when the runs completed before them still count.
+    #[must_use]
+    pub fn runs_run(&self) -> i32 {
+        let off_bat = if self.four || self.six { 0 } else { self.runs };
+        let overthrows = if self.overthrows_to_boundary {
+            0
+        } else {
+            self.overthrows.unwrap_or(0)
+        };
+        off_bat
+            + overthrows
+            + self.byes.unwrap_or(0)
+            + self.leg_byes.unwrap_or(0)
+            + self.wide.map_or(0, |wide| (wide - 1).max(0))
+    }
+
+    /// How many times the batters crossed. On a run out the runs completed
+    /// before the wicket fell decide this, otherwise it's the runs run.
+    #[must_use]
+    pub fn runs_crossed(&self) -> i32 {
+        self.wicket
+            .iter()
+            .flatten()
+            .find_map(|wicket| wicket.completed_runs)
+            .unwrap_or_else(|| self.runs_run())
+    }
+
+    /// Whether the batters changed ends on this delivery
+    #[must_use]
+    pub fn rotates_strike(&self) -> bool {
+        self.runs_crossed() % 2 == 1
+    }
+
     /// # Errors
     ///
     /// Will return an error based on the problem encountered during validation
@@ -94,6 +365,35 @@ impl BallOutcome {
 
         Ok(())
     }
+
+    /// Deserializes a `BallOutcome` and checks it with `validate`, so a corrupt
+    /// stored ball is rejected rather than loaded. Plain `Deserialize` skips
+    /// the check.
+    ///
+    /// # Errors
+    ///
+    /// Will return the deserializer's error if the data is malformed or fails validation
+    pub fn deserialize_validated<'de, D>(deserializer: D) -> Result<BallOutcome, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let outcome = BallOutcome::deserialize(deserializer)?;
+        outcome.validate().map_err(de::Error::custom)?;
+        Ok(outcome)
+    }
+
+    /// Parses a `BallOutcome` from JSON and checks it with `validate`
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the JSON is malformed or the ball fails validation
+    #[cfg(feature = "cricsheet")]
+    pub fn from_json_validated(s: &str) -> Result<BallOutcome, BallOutcomeValidation> {
+        let outcome: BallOutcome = serde_json::from_str(s)
+            .map_err(|e| BallOutcomeValidation::Deserialize(e.to_string()))?;
+        outcome.validate()?;
+        Ok(outcome)
+    }
 }
 
 #[cfg(test)]
@@ -109,6 +409,7 @@ mod tests {
         let wicket = Wicket {
             player_out: "John Doe".to_string(),
             kind: "bowled".to_string(),
+            ..Default::default()
         };
         assert_eq!(wicket.player_out, "John Doe");
         assert_eq!(wicket.kind, "bowled");
@@ -119,6 +420,7 @@ mod tests {
         let wicket = Wicket {
             player_out: "Jane Smith".to_string(),
             kind: "caught".to_string(),
+            ..Default::default()
         };
         let cloned = wicket.clone();
         assert_eq!(wicket, cloned);
@@ -137,10 +439,12 @@ mod tests {
         let wicket1 = vec![Wicket {
             player_out: "Player1".to_string(),
             kind: "bowled".to_string(),
+            ..Default::default()
         }];
         let wicket2 = vec![Wicket {
             player_out: "Player1".to_string(),
             kind: "bowled".to_string(),
+            ..Default::default()
         }];
         assert_eq!(BallEvents::Wicket(wicket1), BallEvents::Wicket(wicket2));
     }
@@ -217,6 +521,7 @@ mod tests {
         let wicket = vec![Wicket {
             player_out: "Batsman1".to_string(),
             kind: "bowled".to_string(),
+            ..Default::default()
         }];
 
         let outcome = BallOutcome::new(
@@ -355,6 +660,7 @@ mod tests {
                 assert_eq!(event1, "Four");
                 assert_eq!(event2, "Six");
             }
+            other => panic!("Unexpected error {other:?}"),
         }
     }
 
@@ -375,6 +681,7 @@ mod tests {
                 assert_eq!(event1, "Bye");
                 assert_eq!(event2, "Leg Bye");
             }
+            other => panic!("Unexpected error {other:?}"),
         }
     }
 
@@ -386,6 +693,7 @@ mod tests {
         let wicket = vec![Wicket {
             player_out: "Batsman1".to_string(),
             kind: "caught".to_string(),
+            ..Default::default()
         }];
 
         let outcome = BallOutcome::new(
@@ -398,4 +706,59 @@ mod tests {
 
         assert!(outcome.validate().is_ok());
     }
+
+    fn four_and_six_json() -> String {
+        let outcome = BallOutcome::new(
+            4,
+            vec![BallEvents::Four, BallEvents::Six],
+            create_test_player("Batsman1"),
+            create_test_player("Batsman2"),
+            create_test_player("Bowler"),
+        );
+        serde_json::to_string(&outcome).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_validated_rejects_invalid_outcome() {
+        let json = four_and_six_json();
+        let raw: BallOutcome = serde_json::from_str(&json).unwrap();
+        assert!(raw.four && raw.six);
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        assert!(BallOutcome::deserialize_validated(&mut deserializer).is_err());
+    }
+
+    #[cfg(feature = "cricsheet")]
+    #[test]
+    fn test_from_json_validated() {
+        let result = BallOutcome::from_json_validated(&four_and_six_json());
+        assert!(matches!(
+            result,
+            Err(BallOutcomeValidation::DoubleOutcome(_, _))
+        ));
+        assert!(matches!(
+            BallOutcome::from_json_validated("not json"),
+            Err(BallOutcomeValidation::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    fn test_dismissal_kind() {
+        let kind = |kind: &str| kind.parse::<DismissalKind>().unwrap();
+        assert_eq!(kind("caught and bowled"), DismissalKind::CaughtAndBowled);
+        assert_eq!(kind(" LBW "), DismissalKind::Lbw);
+        assert_eq!(kind("retired not out"), DismissalKind::RetiredNotOut);
+        assert_eq!(kind("unknown"), DismissalKind::Other("unknown".to_string()));
+
+        assert!(kind("retired out").is_wicket());
+        assert!(!kind("retired out").credited_to_bowler());
+        assert!(!kind("retired hurt").is_wicket());
+        assert!(!kind("retired not out").is_wicket());
+        assert!(!kind("retired other").is_wicket());
+        assert!(kind("unknown").is_wicket());
+        assert!(kind("stumped").credited_to_bowler());
+        assert!(!kind("run out").credited_to_bowler());
+        assert!(kind("hit the ball twice").is_wicket());
+        assert!(!kind("hit the ball twice").credited_to_bowler());
+    }
 }
@@ -1,7 +1,16 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::{hash_map::Entry, HashMap};
-
-use super::{innings::Innings, player::Team};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{
+    dls,
+    innings::{ForfeitBy, Innings},
+    player::{Player, Team},
+    scorecard::{Extras, Scorecard},
+};
+use crate::error::{MatchCreationError, ResultMismatch};
 
 /// A complete cricket match with teams, innings, and result calculation.
 ///
@@ -27,10 +36,12 @@ use super::{innings::Innings, player::Team};
 /// let team1 = Team {
 ///     name: "Australia".to_string(),
 ///     players: vec![Player::new("Steve Smith".to_string())],
+///     ..Default::default()
 /// };
 /// let team2 = Team {
 ///     name: "England".to_string(),
 ///     players: vec![Player::new("Joe Root".to_string())],
+///     ..Default::default()
 /// };
 ///
 /// // Create match
@@ -72,7 +83,13 @@ pub struct Match {
     pub id: String,
     pub title: String,
     pub venue: Option<String>,
-    pub date: Option<String>,
+    /// The name of the home side, if there is one, which scoreboards list first
+    #[serde(default)]
+    pub home_team: Option<String>,
+    /// Every day the match was scheduled to be played on. Older files with a
+    /// single `date` are read as one date.
+    #[serde(default, alias = "date", deserialize_with = "deserialize_dates")]
+    pub dates: Vec<String>,
     pub match_type: MatchType,
     pub team1: Team,
     pub team2: Team,
@@ -82,10 +99,38 @@ pub struct Match {
     /// Final result of the match if completed
     pub result: Option<MatchResult>,
     pub event: Option<String>,
+    /// Position of the match in its format's history (e.g. the Nth ODI)
+    #[serde(default)]
+    pub match_number: Option<i32>,
+    /// Season the match was played in (e.g. "2023/24")
+    #[serde(default)]
+    pub season: Option<String>,
+    /// Super over innings played to break a tie, in batting order
+    #[serde(default)]
+    pub super_overs: Vec<Innings>,
+    /// Overs allowed per innings when the match sets its own limit, e.g. 10 for
+    /// a T10 played as `MatchType::Other`
+    #[serde(default)]
+    pub max_overs: Option<i32>,
+    /// Legal deliveries per over when the match doesn't use six ball overs
+    #[serde(default)]
+    pub balls_per_over: Option<i32>,
+    /// Players named player of the match, usually just one
+    #[serde(default)]
+    pub player_of_match: Vec<String>,
+    /// "male" or "female", as cricsheet records it
+    #[serde(default)]
+    pub gender: Option<String>,
+    /// "international" or "club"
+    #[serde(default)]
+    pub team_type: Option<String>,
 }
 
 /// Types of cricket matches
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Deserializing accepts the common aliases handled by `from_str` (e.g. "ODI",
+/// "twenty20") as well as the variant names.
+#[derive(Debug, Clone, Serialize)]
 pub enum MatchType {
     /// Test cricket
     Test,
@@ -110,25 +155,80 @@ pub enum MatchStatus {
 
 /// Final result of a completed match
 /// Method, if it exists, gives a method for the result (e.g. D/L)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MatchResult {
     Team1Won {
         margin: WinMargin,
-        method: Option<String>,
+        method: Option<ResultMethod>,
     },
     Team2Won {
         margin: WinMargin,
-        method: Option<String>,
+        method: Option<ResultMethod>,
     },
     Tie {
-        method: Option<String>,
+        method: Option<ResultMethod>,
     },
     Draw,
     NoResult,
 }
 
+/// How a result was reached when it wasn't decided on the scores alone.
+///
+/// Serialized as the method's name as cricsheet writes it, e.g. "D/L".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResultMethod {
+    /// Decided by the Duckworth-Lewis(-Stern) method
+    DuckworthLewis,
+    /// Decided by the VJD method
+    Vjd,
+    Awarded,
+    /// Conceded because a side forfeited
+    Forfeit,
+    Rain,
+    BadLight,
+    /// Any other method, as written
+    Other(String),
+}
+
+/// How a match that finished level was decided
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Tiebreak {
+    /// Won on a super over
+    SuperOver,
+    /// Every super over was tied, so decided on boundaries hit
+    BoundaryCount,
+}
+
+/// How a match was decided, combining the result, margin and any tiebreak
+#[derive(Debug, Clone, PartialEq)]
+pub enum Decision {
+    ByRuns(u32),
+    ByWickets(u8),
+    ByInningsAndRuns(u32),
+    /// Scores finished level, with the tiebreak that decided it if there was one
+    Tie(Option<Tiebreak>),
+    Draw,
+    /// Abandoned or otherwise no result
+    Abandoned,
+    Awarded,
+}
+
+/// How the side that's ahead in a match in progress is ahead
+#[derive(Debug, Clone, PartialEq)]
+pub enum Ahead {
+    /// Leading on aggregate by this many runs, e.g. on first innings in a Test
+    Lead(i32),
+    /// Ahead of the DLS par score by this many runs in a chase with reduced overs
+    OfPar(i32),
+    /// Ahead in a chase, comparing the chasing side's run rate to the rate needed
+    OnChase {
+        run_rate: f64,
+        required_run_rate: f64,
+    },
+}
+
 /// Margin of victory in a cricket match
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum WinMargin {
     /// Victory by a certain number of runs
     Runs(u32),
@@ -136,17 +236,21 @@ pub enum WinMargin {
     Wickets(u8),
     /// Victory by award (forfeit, disqualification, etc.) - no playing margin
     Award,
+    /// Scores finished level and the tie was broken, e.g. by a super over
+    Tiebreak(Tiebreak),
 }
 
 impl Match {
-    /// Shorthand to create a new match with the given details (not all fields)
+    /// Shorthand to create a new match with the given details (not all fields).
+    /// The team names aren't checked, use `try_new` to reject duplicates
     #[must_use]
     pub fn new(id: String, title: String, match_type: MatchType, team1: Team, team2: Team) -> Self {
         Match {
             id,
             title,
             venue: None,
-            date: None,
+            home_team: None,
+            dates: Vec::new(),
             match_type,
             team1,
             team2,
@@ -154,29 +258,288 @@ impl Match {
             status: MatchStatus::NotStarted,
             result: None,
             event: None,
+            match_number: None,
+            season: None,
+            super_overs: Vec::new(),
+            max_overs: None,
+            balls_per_over: None,
+            player_of_match: Vec::new(),
+            gender: None,
+            team_type: None,
+        }
+    }
+
+    /// Creates a new match, checking that the two teams are distinct. The result
+    /// calculation and team totals look teams up by name, so two teams with the
+    /// same name can't be told apart.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if `team1` and `team2` have the same name
+    pub fn try_new(
+        id: String,
+        title: String,
+        match_type: MatchType,
+        team1: Team,
+        team2: Team,
+    ) -> Result<Self, MatchCreationError> {
+        if team1.name == team2.name {
+            return Err(MatchCreationError::DuplicateTeamNames(team1.name));
         }
+        Ok(Match::new(id, title, match_type, team1, team2))
+    }
+
+    /// Starts building a match, for setting several of its optional details at
+    /// once (see `MatchBuilder`)
+    pub fn builder(
+        id: String,
+        title: String,
+        match_type: MatchType,
+        team1: Team,
+        team2: Team,
+    ) -> MatchBuilder {
+        MatchBuilder::from(Match::new(id, title, match_type, team1, team2))
     }
 
     /// Sets the venue for the match
-    pub fn with_venue(mut self, venue: String) -> Self {
-        self.venue = Some(venue);
-        self
+    pub fn with_venue(self, venue: String) -> Self {
+        MatchBuilder::from(self).venue(venue).build()
     }
 
-    /// Sets the date for the match
-    pub fn with_date(mut self, date: String) -> Self {
-        self.date = Some(date);
-        self
+    /// Sets the home side by name
+    pub fn with_home_team(self, home_team: String) -> Self {
+        MatchBuilder::from(self).home_team(home_team).build()
+    }
+
+    /// The two teams in the order a scoreboard lists them: the home side first,
+    /// otherwise `team1` then `team2`
+    #[must_use]
+    pub fn teams_in_display_order(&self) -> (&Team, &Team) {
+        if self.home_team.as_ref() == Some(&self.team2.name) {
+            (&self.team2, &self.team1)
+        } else {
+            (&self.team1, &self.team2)
+        }
+    }
+
+    /// Sets the date for a single-day match
+    pub fn with_date(self, date: String) -> Self {
+        MatchBuilder::from(self).date(date).build()
+    }
+
+    /// Sets every date the match is played on
+    pub fn with_dates(self, dates: Vec<String>) -> Self {
+        MatchBuilder::from(self).dates(dates).build()
+    }
+
+    /// The day the match started
+    #[must_use]
+    pub fn first_date(&self) -> Option<&String> {
+        self.dates.first()
+    }
+
+    /// Whether the match is played over more than one day
+    #[must_use]
+    pub fn is_multi_day(&self) -> bool {
+        self.dates.len() > 1
+    }
+
+    /// Sets the season for the match
+    pub fn with_season(self, season: String) -> Self {
+        MatchBuilder::from(self).season(season).build()
+    }
+
+    /// Sets the match's number within its format
+    pub fn with_match_number(self, match_number: i32) -> Self {
+        MatchBuilder::from(self).match_number(match_number).build()
+    }
+
+    /// Key for sorting matches chronologically, as (season, date)
+    #[must_use]
+    pub fn ordering_key(&self) -> (Option<String>, Option<String>) {
+        (self.season.clone(), self.first_date().cloned())
     }
 
     /// Set the event the match is part of
-    pub fn with_event(&mut self, event: String) {
-        self.event = Some(event);
+    pub fn with_event(self, event: String) -> Self {
+        MatchBuilder::from(self).event(event).build()
+    }
+
+    /// Adds an innings to the match. It is numbered, configured and, if it's the
+    /// chase and has no target yet, given a target as in `start_innings`, but the
+    /// match status is left as it is.
+    pub fn add_innings(&mut self, mut innings: Innings) {
+        self.prepare_innings(&mut innings);
+        self.innings.push(innings);
+    }
+
+    /// Numbers an innings about to be added after those already played, applies
+    /// the match's settings to it and sets its target if it's the chase
+    fn prepare_innings(&self, innings: &mut Innings) {
+        innings.innings_number = (self.innings.len() + 1) as u8;
+        self.configure_innings(innings);
+        if innings.target.is_none() {
+            innings.target = self.chase_target(innings);
+        }
+    }
+
+    /// The runs needed to win if `innings`, about to be added, is the chase: the
+    /// second innings of a limited-overs match or the fourth innings of a Test
+    fn chase_target(&self, innings: &Innings) -> Option<i32> {
+        let chase_innings = match self.match_type {
+            MatchType::Test => 4,
+            _ => 2,
+        };
+        if self.innings.len() + 1 != chase_innings {
+            return None;
+        }
+        let (batting_runs, bowling_runs) = if innings.batting_team.name == self.team1.name {
+            (self.team1_total_runs(), self.team2_total_runs())
+        } else {
+            (self.team2_total_runs(), self.team1_total_runs())
+        };
+        Some(bowling_runs - batting_runs + 1)
+    }
+
+    /// Adds a super over innings to the match. Super overs are paired in the
+    /// order they are added, each pair being one super over per side.
+    pub fn add_super_over(&mut self, innings: Innings) {
+        self.super_overs.push(innings);
     }
 
-    /// Adds an innings to the match
-    pub fn add_innings(&mut self, innings: Innings) {
+    /// Starts the next innings of the match and returns it for scoring.
+    ///
+    /// The new innings is numbered after the innings already played, and if it is
+    /// the chase (the second innings of a limited-overs match or the fourth innings
+    /// of a Test) its target is set to the runs the batting side needs to win.
+    /// The match is marked as in progress.
+    pub fn start_innings(&mut self, batting: Team, bowling: Team) -> &mut Innings {
+        let mut innings = Innings::new(batting, bowling);
+        self.prepare_innings(&mut innings);
+
+        self.status = MatchStatus::InProgress;
         self.innings.push(innings);
+        self.innings.last_mut().unwrap()
+    }
+
+    /// Applies the match's over limit and over length, where set, to an innings
+    pub fn configure_innings(&self, innings: &mut Innings) {
+        if self.max_overs.is_some() {
+            innings.max_overs = self.max_overs;
+        }
+        if let Some(balls_per_over) = self.balls_per_over {
+            innings.score.balls_per_over = balls_per_over;
+        }
+    }
+
+    /// Percentage of a full innings' resources (see `dls`) used by the side batting
+    /// first, allowing for an innings cut short with overs still to bowl. The
+    /// innings' over limit falls back to the format's. Returns `None` if there is
+    /// no first innings or it had no over limit.
+    #[must_use]
+    pub fn first_innings_resources_used(&self) -> Option<f64> {
+        let first = self.innings.first()?;
+        let max_overs = first.max_overs.or(self.match_type.max_overs())?;
+        let overs_remaining = f64::from(max_overs)
+            - f64::from(first.score.legal_balls()) / f64::from(first.score.balls_per_over);
+        Some(
+            dls::resources_remaining(f64::from(max_overs), 0)
+                - dls::resources_remaining(overs_remaining, first.score.wickets_lost),
+        )
+    }
+
+    /// Revises the second innings' target for an innings reduced to `revised_overs`
+    /// before it started, from the first innings total and the resources each side
+    /// had. The second innings' target and over limit are updated and the target
+    /// is returned, or `None` if either innings is missing or the first innings had
+    /// no over limit.
+    pub fn set_dls_target(&mut self, revised_overs: i32) -> Option<i32> {
+        let team1_resources = self.first_innings_resources_used()?;
+        let team1_score = self.innings.first()?.score.runs;
+        let team2_resources = dls::resources_remaining(f64::from(revised_overs), 0);
+        let target = dls::revised_target(team1_score, team1_resources, team2_resources);
+
+        let chase = self.innings.get_mut(1)?;
+        chase.target = Some(target);
+        chase.max_overs = Some(revised_overs);
+        Some(target)
+    }
+
+    /// The team that's currently ahead and how. In a limited overs chase this
+    /// compares the run rate to the rate required, or the score to the DLS par
+    /// score if the chase was reduced with `set_dls_target`. Otherwise the side
+    /// with more runs on aggregate leads. Returns `None` before two innings exist
+    /// or when neither side is ahead.
+    #[must_use]
+    pub fn who_is_ahead(&self) -> Option<(String, Ahead)> {
+        if self.innings.len() < 2 {
+            return None;
+        }
+        let current = self.innings.last()?;
+        let batting = current.batting_team.name.clone();
+        let bowling = current.bowling_team.name.clone();
+
+        if let (Some(target), Some(max_overs)) = (
+            current.target,
+            current
+                .max_overs
+                .or(self.max_overs)
+                .or(self.match_type.max_overs()),
+        ) {
+            let score = &current.score;
+            let balls_remaining = max_overs * score.balls_per_over - score.legal_balls();
+            let first_max_overs = self.innings[0]
+                .max_overs
+                .or(self.max_overs)
+                .or(self.match_type.max_overs());
+            if score.legal_balls() > 0 && balls_remaining > 0 {
+                if first_max_overs.is_some_and(|overs| max_overs < overs) {
+                    let overs_remaining =
+                        f64::from(balls_remaining) / f64::from(score.balls_per_over);
+                    let resources = dls::resources_remaining(f64::from(max_overs), 0);
+                    let used =
+                        resources - dls::resources_remaining(overs_remaining, score.wickets_lost);
+                    let par = (f64::from(target - 1) * used / resources).round() as i32;
+                    return match score.runs.cmp(&par) {
+                        std::cmp::Ordering::Greater => {
+                            Some((batting, Ahead::OfPar(score.runs - par)))
+                        }
+                        std::cmp::Ordering::Less => Some((bowling, Ahead::OfPar(par - score.runs))),
+                        std::cmp::Ordering::Equal => None,
+                    };
+                }
+                let run_rate = f64::from(score.runs) * f64::from(score.balls_per_over)
+                    / f64::from(score.legal_balls());
+                let required_run_rate = f64::from(target - score.runs)
+                    * f64::from(score.balls_per_over)
+                    / f64::from(balls_remaining);
+                let leader = if run_rate >= required_run_rate {
+                    batting
+                } else {
+                    bowling
+                };
+                return Some((
+                    leader,
+                    Ahead::OnChase {
+                        run_rate,
+                        required_run_rate,
+                    },
+                ));
+            }
+        }
+
+        let (team1_runs, team2_runs) = (self.team1_total_runs(), self.team2_total_runs());
+        match team1_runs.cmp(&team2_runs) {
+            std::cmp::Ordering::Greater => Some((
+                self.team1.name.clone(),
+                Ahead::Lead(team1_runs - team2_runs),
+            )),
+            std::cmp::Ordering::Less => Some((
+                self.team2.name.clone(),
+                Ahead::Lead(team2_runs - team1_runs),
+            )),
+            std::cmp::Ordering::Equal => None,
+        }
     }
 
     /// Sets the match status
@@ -191,7 +554,7 @@ impl Match {
     }
 
     /// Sets the match result with method information (e.g. "D/L", "VJD", etc.)
-    pub fn set_result_with_method(&mut self, result: MatchResult, method: Option<String>) {
+    pub fn set_result_with_method(&mut self, result: MatchResult, method: Option<ResultMethod>) {
         let result_with_method = match result {
             MatchResult::Team1Won { margin, .. } => MatchResult::Team1Won { margin, method },
             MatchResult::Team2Won { margin, .. } => MatchResult::Team2Won { margin, method },
@@ -233,6 +596,176 @@ impl Match {
             .sum()
     }
 
+    /// Whether a super over is needed: a limited overs match whose two main
+    /// innings are complete, with the scores level. Tests don't have super overs.
+    #[must_use]
+    pub fn requires_super_over(&self) -> bool {
+        let limited_overs = self.max_overs.or(self.match_type.max_overs()).is_some();
+        let innings_complete = self.innings.len() == 2
+            && self.innings.iter().all(|innings| {
+                innings.finished
+                    || innings.score.wickets_left <= 0
+                    || innings.max_overs.is_some_and(|max_overs| {
+                        innings.score.legal_balls() >= max_overs * innings.score.balls_per_over
+                    })
+            });
+        limited_overs && innings_complete && self.ended_level()
+    }
+
+    /// Runs scored off the bat by the named team across all their innings,
+    /// leaving out extras and penalty runs
+    #[must_use]
+    pub fn team_batting_runs(&self, team: &str) -> i32 {
+        self.innings
+            .iter()
+            .filter(|innings| innings.batting_team.name == team)
+            .map(Innings::runs_off_the_bat)
+            .sum()
+    }
+
+    /// Balls per run (see `Innings::balls_per_run`) of each innings, numbered from
+    /// 1 in batting order. Innings without a run are left out.
+    #[must_use]
+    pub fn innings_efficiency(&self) -> Vec<(u8, f64)> {
+        self.innings
+            .iter()
+            .zip(1..)
+            .filter_map(|(innings, number)| Some((number, innings.balls_per_run()?)))
+            .collect()
+    }
+
+    /// Whether both teams batted and finished with the same total in the main
+    /// innings, however any tie was then broken
+    #[must_use]
+    pub fn ended_level(&self) -> bool {
+        let batted = |name: &str| {
+            self.innings
+                .iter()
+                .any(|innings| innings.batting_team.name == name)
+        };
+        batted(&self.team1.name)
+            && batted(&self.team2.name)
+            && self.team1_total_runs() == self.team2_total_runs()
+    }
+
+    /// Gets the total number of fours and sixes hit across all innings
+    #[must_use]
+    pub fn total_boundaries(&self) -> (i32, i32) {
+        self.innings
+            .iter()
+            .flat_map(|innings| &innings.batting_team.players)
+            .fold((0, 0), |(fours, sixes), player| {
+                (fours + player.fours, sixes + player.sixes)
+            })
+    }
+
+    /// Each player's figures summed across the match's innings, leaving out super
+    /// overs, in the order they first appear. Batting figures come from the
+    /// innings a player batted in and bowling figures from those they bowled in,
+    /// and whether they're out is from their latest innings with the bat.
+    #[must_use]
+    pub fn player_totals(&self) -> Vec<Player> {
+        let mut totals: Vec<Player> = Vec::new();
+        let total_for = |totals: &mut Vec<Player>, name: &str| -> usize {
+            totals
+                .iter()
+                .position(|player| player.name == name)
+                .unwrap_or_else(|| {
+                    totals.push(Player::new(name.to_string()));
+                    totals.len() - 1
+                })
+        };
+        for innings in &self.innings {
+            for batter in &innings.batting_team.players {
+                let index = total_for(&mut totals, &batter.name);
+                let total = &mut totals[index];
+                total.runs += batter.runs;
+                total.balls_faced += batter.balls_faced;
+                total.fours += batter.fours;
+                total.sixes += batter.sixes;
+                if batter.out || batter.balls_faced != 0 {
+                    total.out = batter.out;
+                    total.dismissal.clone_from(&batter.dismissal);
+                }
+            }
+            for bowler in &innings.bowling_team.players {
+                let index = total_for(&mut totals, &bowler.name);
+                let total = &mut totals[index];
+                total.balls_bowled += bowler.balls_bowled;
+                total.runs_conceded += bowler.runs_conceded;
+                total.wickets_taken += bowler.wickets_taken;
+                total.maidens += bowler.maidens;
+                total.wides += bowler.wides;
+                total.no_balls += bowler.no_balls;
+                total.dot_balls += bowler.dot_balls;
+            }
+        }
+        totals
+    }
+
+    /// Players who both batted and bowled in the match, with their match totals
+    /// from `player_totals`
+    #[must_use]
+    pub fn all_rounders(&self) -> Vec<Player> {
+        self.player_totals()
+            .into_iter()
+            .filter(|player| player.balls_faced > 0 && player.balls_bowled > 0)
+            .collect()
+    }
+
+    /// The extras conceded by `bowling_team` across the innings it bowled in,
+    /// leaving out super overs
+    #[must_use]
+    pub fn extras_conceded_by(&self, bowling_team: &str) -> Extras {
+        self.innings
+            .iter()
+            .filter(|innings| innings.bowling_team.name == bowling_team)
+            .map(|innings| Extras::from(innings.run_sources()))
+            .fold(Extras::default(), |total, extras| Extras {
+                wides: total.wides + extras.wides,
+                no_balls: total.no_balls + extras.no_balls,
+                byes: total.byes + extras.byes,
+                leg_byes: total.leg_byes + extras.leg_byes,
+                penalties: total.penalties + extras.penalties,
+                total: total.total + extras.total,
+            })
+    }
+
+    /// The team that conceded the most extras in the match, or `None` before any
+    /// innings has started. If both conceded the same, it's `team1`.
+    #[must_use]
+    pub fn most_extras_conceding_team(&self) -> Option<String> {
+        if self.innings.is_empty() {
+            return None;
+        }
+        let team1 = self.extras_conceded_by(&self.team1.name).total;
+        let team2 = self.extras_conceded_by(&self.team2.name).total;
+        let team = if team2 > team1 {
+            &self.team2
+        } else {
+            &self.team1
+        };
+        Some(team.name.clone())
+    }
+
+    /// Gets the percentage of legal deliveries in the match that were dot balls.
+    /// Returns 0.0 if no balls have been bowled
+    #[must_use]
+    pub fn dot_ball_percentage(&self) -> f64 {
+        let (dot_balls, balls_bowled) = self
+            .innings
+            .iter()
+            .flat_map(|innings| &innings.bowling_team.players)
+            .fold((0, 0), |(dots, balls), player| {
+                (dots + player.dot_balls, balls + player.balls_bowled)
+            });
+        if balls_bowled == 0 {
+            0.0
+        } else {
+            f64::from(dot_balls) / f64::from(balls_bowled) * 100.0
+        }
+    }
+
     /// Calculate the match result based on innings data.
     ///
     /// This method analyzes all completed innings to determine the match winner and margin of victory.
@@ -250,8 +783,8 @@ impl Match {
     /// ```
     /// use cricket_scoring::{Match, MatchType, Team, Player, Innings, MatchResult};
     ///
-    /// let team1 = Team { name: "Team A".to_string(), players: vec![] };
-    /// let team2 = Team { name: "Team B".to_string(), players: vec![] };
+    /// let team1 = Team { name: "Team A".to_string(), players: vec![], ..Default::default() };
+    /// let team2 = Team { name: "Team B".to_string(), players: vec![], ..Default::default() };
     /// let mut cricket_match = Match::new("M001".to_string(), "Test".to_string(),
     ///                                   MatchType::OD, team1.clone(), team2.clone());
     ///
@@ -276,6 +809,23 @@ impl Match {
             return;
         }
 
+        // A fielding side that forfeits concedes the match, whatever the scores
+        if let Some(innings) = self
+            .innings
+            .iter()
+            .find(|innings| innings.forfeited && innings.forfeited_by == ForfeitBy::Bowling)
+        {
+            let margin = WinMargin::Award;
+            let method = Some(ResultMethod::Forfeit);
+            self.result = Some(if innings.batting_team.name == self.team1.name {
+                MatchResult::Team1Won { margin, method }
+            } else {
+                MatchResult::Team2Won { margin, method }
+            });
+            self.status = MatchStatus::Completed;
+            return;
+        }
+
         let mut scores: HashMap<String, Vec<i32>> = HashMap::new();
         let mut teams: Vec<String> = vec![];
         let mut bowling_team = String::new();
@@ -300,18 +850,13 @@ impl Match {
 
         // 0 or 1 innings - game not complete
         let not_finished = scores.len() < 2;
-        // last team didn't score enough runs, but had wickets left
-        let is_draw = scores
-            .get(&batting_team)
-            .unwrap_or(&vec![])
-            .iter()
-            .sum::<i32>()
-            < scores
-                .get(&bowling_team)
-                .unwrap_or(&vec![])
-                .iter()
-                .sum::<i32>()
-            && last_innings_wickets_left.unwrap_or(0) > 0;
+        // last team didn't score enough runs, but had wickets left. In a Test the
+        // scores finishing level is only a tie if the last team was all out.
+        let batting_total: i32 = scores.get(&batting_team).unwrap_or(&vec![]).iter().sum();
+        let bowling_total: i32 = scores.get(&bowling_team).unwrap_or(&vec![]).iter().sum();
+        let fell_short = batting_total < bowling_total
+            || (batting_total == bowling_total && matches!(self.match_type, MatchType::Test));
+        let is_draw = fell_short && last_innings_wickets_left.unwrap_or(0) > 0;
 
         if not_finished || is_draw {
             self.result = Some(MatchResult::Draw);
@@ -345,7 +890,7 @@ impl Match {
                     })
                 }
             }
-            std::cmp::Ordering::Equal => Some(MatchResult::Tie { method: None }),
+            std::cmp::Ordering::Equal => Some(self.resolve_tie()),
             std::cmp::Ordering::Less => {
                 let margin = self.calculate_win_margin(
                     &team_b,
@@ -371,6 +916,89 @@ impl Match {
         self.status = MatchStatus::Completed;
     }
 
+    /// Checks the stored result against one recalculated from the innings, e.g.
+    /// to confirm a result loaded from a file agrees with the scores. Awarded
+    /// results and those decided by a method such as DLS can't be recalculated,
+    /// so are reported as mismatches.
+    ///
+    /// # Errors
+    ///
+    /// Will return the stored and recalculated results if they differ
+    pub fn verify_result(&self) -> Result<(), ResultMismatch> {
+        let mut recalculated = self.clone();
+        recalculated.calculate_result();
+        if recalculated.result == self.result {
+            Ok(())
+        } else {
+            Err(ResultMismatch {
+                stored: self.result.clone(),
+                calculated: recalculated.result,
+            })
+        }
+    }
+
+    /// Decides a tied match from its super overs. Each pair of super overs is
+    /// checked in turn and the first one that isn't tied decides the match. If
+    /// every super over is tied the side that hit more boundaries across the
+    /// match and its super overs wins, otherwise the match stays tied.
+    fn resolve_tie(&self) -> MatchResult {
+        if self.super_overs.is_empty() {
+            return MatchResult::Tie { method: None };
+        }
+
+        let mut winner = None;
+        for pair in self.super_overs.chunks(2) {
+            let [first, second] = pair else {
+                break;
+            };
+            winner = match first.score.runs.cmp(&second.score.runs) {
+                std::cmp::Ordering::Greater => {
+                    Some((&first.batting_team.name, Tiebreak::SuperOver))
+                }
+                std::cmp::Ordering::Less => Some((&second.batting_team.name, Tiebreak::SuperOver)),
+                std::cmp::Ordering::Equal => continue,
+            };
+            break;
+        }
+
+        if winner.is_none() {
+            let team1_boundaries = self.team_boundaries(&self.team1.name);
+            let team2_boundaries = self.team_boundaries(&self.team2.name);
+            winner = match team1_boundaries.cmp(&team2_boundaries) {
+                std::cmp::Ordering::Greater => Some((&self.team1.name, Tiebreak::BoundaryCount)),
+                std::cmp::Ordering::Less => Some((&self.team2.name, Tiebreak::BoundaryCount)),
+                std::cmp::Ordering::Equal => None,
+            };
+        }
+
+        let Some((winner, tiebreak)) = winner else {
+            return MatchResult::Tie { method: None };
+        };
+        let margin = WinMargin::Tiebreak(tiebreak);
+        if *winner == self.team1.name {
+            MatchResult::Team1Won {
+                margin,
+                method: None,
+            }
+        } else {
+            MatchResult::Team2Won {
+                margin,
+                method: None,
+            }
+        }
+    }
+
+    /// Fours and sixes hit by a team across its innings and super overs
+    fn team_boundaries(&self, team: &str) -> i32 {
+        self.innings
+            .iter()
+            .chain(&self.super_overs)
+            .filter(|innings| innings.batting_team.name == team)
+            .flat_map(|innings| &innings.batting_team.players)
+            .map(|player| player.fours + player.sixes)
+            .sum()
+    }
+
     /// Calculate the margin of victory
     fn calculate_win_margin(
         &self,
@@ -396,6 +1024,169 @@ impl Match {
         }
     }
 
+    /// How the match was decided, or `None` if it has no result yet
+    #[must_use]
+    pub fn decision(&self) -> Option<Decision> {
+        let margin = match &self.result {
+            Some(MatchResult::Team1Won { margin, .. } | MatchResult::Team2Won { margin, .. }) => {
+                margin
+            }
+            Some(MatchResult::Tie { .. }) => return Some(Decision::Tie(None)),
+            Some(MatchResult::Draw) => return Some(Decision::Draw),
+            Some(MatchResult::NoResult) => return Some(Decision::Abandoned),
+            None if matches!(self.status, MatchStatus::Abandoned) => {
+                return Some(Decision::Abandoned)
+            }
+            None => return None,
+        };
+        Some(match margin {
+            WinMargin::Runs(runs) if self.is_innings_victory() => Decision::ByInningsAndRuns(*runs),
+            WinMargin::Runs(runs) => Decision::ByRuns(*runs),
+            WinMargin::Wickets(wickets) => Decision::ByWickets(*wickets),
+            WinMargin::Award => Decision::Awarded,
+            WinMargin::Tiebreak(tiebreak) => Decision::Tie(Some(tiebreak.clone())),
+        })
+    }
+
+    /// How a tie in the main innings was broken, taken from the result's margin.
+    /// `None` unless a super over or boundary count decided the match.
+    #[must_use]
+    pub fn tiebreak(&self) -> Option<&Tiebreak> {
+        match &self.result {
+            Some(
+                MatchResult::Team1Won {
+                    margin: WinMargin::Tiebreak(tiebreak),
+                    ..
+                }
+                | MatchResult::Team2Won {
+                    margin: WinMargin::Tiebreak(tiebreak),
+                    ..
+                },
+            ) => Some(tiebreak),
+            _ => None,
+        }
+    }
+
+    /// How close the finish was, for finding e.g. the closest match of a
+    /// tournament, where lower is closer. A tie scores 0 and a win by 1 run or 1
+    /// wicket scores 1, and each further wicket in hand counts as
+    /// `WinMargin::RUNS_PER_WICKET` runs, so a 3 wicket win scores 21. An innings
+    /// victory scores a full ten wickets on top of its runs margin. Draws, awards
+    /// and abandoned matches have no margin, so give `None`.
+    #[must_use]
+    pub fn closeness(&self) -> Option<f64> {
+        let wickets =
+            |wickets: u8| 1.0 + (f64::from(wickets) - 1.0).max(0.0) * WinMargin::RUNS_PER_WICKET;
+        match self.decision()? {
+            Decision::Tie(_) => Some(0.0),
+            Decision::ByRuns(runs) => Some(f64::from(runs)),
+            Decision::ByWickets(w) => Some(wickets(w)),
+            Decision::ByInningsAndRuns(runs) => Some(f64::from(runs) + wickets(10)),
+            Decision::Draw | Decision::Abandoned | Decision::Awarded => None,
+        }
+    }
+
+    /// A one-line description of the result, e.g. "England won by 2 wickets".
+    ///
+    /// Forfeited innings count as completed innings of 0 runs, so a match in which
+    /// both sides forfeited an innings is not reported as an innings victory.
+    #[must_use]
+    pub fn result_summary(&self) -> String {
+        let (team, margin, method) = match &self.result {
+            Some(MatchResult::Team1Won { margin, method }) => (&self.team1.name, margin, method),
+            Some(MatchResult::Team2Won { margin, method }) => (&self.team2.name, margin, method),
+            Some(MatchResult::Tie { method }) => {
+                return match method {
+                    Some(method) => format!("Match tied ({method})"),
+                    None => String::from("Match tied"),
+                }
+            }
+            Some(MatchResult::Draw) => return String::from("Match drawn"),
+            Some(MatchResult::NoResult) => return String::from("No result"),
+            None => return String::from("Match in progress"),
+        };
+
+        let plural = |n: u32| if n == 1 { "" } else { "s" };
+        let mut summary = match margin {
+            WinMargin::Runs(runs) if self.is_innings_victory() => {
+                format!("{team} won by an innings and {runs} run{}", plural(*runs))
+            }
+            WinMargin::Runs(runs) => format!("{team} won by {runs} run{}", plural(*runs)),
+            WinMargin::Wickets(wickets) => {
+                format!(
+                    "{team} won by {wickets} wicket{}",
+                    plural(u32::from(*wickets))
+                )
+            }
+            WinMargin::Award => format!("{team} awarded the match"),
+            WinMargin::Tiebreak(Tiebreak::SuperOver) => format!("{team} won the super over"),
+            WinMargin::Tiebreak(Tiebreak::BoundaryCount) => {
+                format!("{team} won on boundary count")
+            }
+        };
+        if let Some(method) = method {
+            summary.push_str(&format!(" ({method})"));
+        }
+        summary
+    }
+
+    /// A structured scorecard of every innings, for serializing to JSON
+    #[must_use]
+    pub fn scorecard_json(&self) -> Scorecard {
+        Scorecard::new(self)
+    }
+
+    /// Column names for the rows from `to_summary_row`
+    pub const SUMMARY_CSV_HEADER: &'static str =
+        "date,format,team1,team2,team1_total,team2_total,result,player_of_match";
+
+    /// The match as one CSV row, with the columns in `SUMMARY_CSV_HEADER`. The date
+    /// is the first day of the match, and several players of the match are
+    /// separated by semicolons.
+    #[must_use]
+    pub fn to_summary_row(&self) -> String {
+        let format = match &self.match_type {
+            MatchType::Test => "Test",
+            MatchType::OD => "OD",
+            MatchType::T20 => "T20",
+            MatchType::Other(name) => name,
+        };
+        [
+            self.first_date().cloned().unwrap_or_default(),
+            format.to_string(),
+            self.team1.name.clone(),
+            self.team2.name.clone(),
+            self.team1_total_runs().to_string(),
+            self.team2_total_runs().to_string(),
+            self.result_summary(),
+            self.player_of_match.join("; "),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+
+    /// The name of the winning team, or `None` if there's no winner (yet)
+    #[must_use]
+    pub fn winner(&self) -> Option<&str> {
+        match &self.result {
+            Some(MatchResult::Team1Won { .. }) => Some(&self.team1.name),
+            Some(MatchResult::Team2Won { .. }) => Some(&self.team2.name),
+            _ => None,
+        }
+    }
+
+    /// The name of the losing team, or `None` if there's no winner (yet)
+    #[must_use]
+    pub fn loser(&self) -> Option<&str> {
+        match &self.result {
+            Some(MatchResult::Team1Won { .. }) => Some(&self.team2.name),
+            Some(MatchResult::Team2Won { .. }) => Some(&self.team1.name),
+            _ => None,
+        }
+    }
+
     /// Check if this is an innings victory (team won without needing all their innings)
     #[must_use]
     pub fn is_innings_victory(&self) -> bool {
@@ -416,31 +1207,300 @@ impl Match {
     }
 }
 
-impl Default for MatchType {
-    fn default() -> Self {
-        MatchType::Other("Unknown".to_string())
+/// Builds a `Match` with its optional details, e.g.
+///
+/// ```
+/// use cricket_scoring::{Match, MatchType, Player, Team};
+///
+/// let team = |name: &str| Team {
+///     name: name.to_string(),
+///     players: vec![Player::new(format!("{name} opener"))],
+///     ..Default::default()
+/// };
+/// let cricket_match = Match::builder(
+///     "AUS_ENG_2025".to_string(),
+///     "Australia vs England".to_string(),
+///     MatchType::T20,
+///     team("Australia"),
+///     team("England"),
+/// )
+/// .venue("Melbourne Cricket Ground".to_string())
+/// .home_team("Australia".to_string())
+/// .event("T20I Series".to_string())
+/// .build();
+///
+/// assert_eq!(cricket_match.event, Some("T20I Series".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct MatchBuilder {
+    cricket_match: Match,
+}
+
+impl From<Match> for MatchBuilder {
+    fn from(cricket_match: Match) -> Self {
+        MatchBuilder { cricket_match }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::scoring::player::Player;
+impl MatchBuilder {
+    /// Sets the venue for the match
+    pub fn venue(mut self, venue: String) -> Self {
+        self.cricket_match.venue = Some(venue);
+        self
+    }
 
-    fn create_test_team(name: &str) -> Team {
-        Team {
-            name: name.to_string(),
-            players: vec![
-                Player::new("Player1".to_string()),
-                Player::new("Player2".to_string()),
-            ],
-        }
+    /// Sets the home side by name
+    pub fn home_team(mut self, home_team: String) -> Self {
+        self.cricket_match.home_team = Some(home_team);
+        self
     }
 
-    fn create_test_innings(batting_team: Team, bowling_team: Team, runs: i32) -> Innings {
-        let mut innings = Innings::new(batting_team, bowling_team);
-        innings.score.runs = runs;
-        innings
+    /// Sets the date for a single-day match
+    pub fn date(mut self, date: String) -> Self {
+        self.cricket_match.dates = vec![date];
+        self
+    }
+
+    /// Sets every date the match is played on
+    pub fn dates(mut self, dates: Vec<String>) -> Self {
+        self.cricket_match.dates = dates;
+        self
+    }
+
+    /// Sets the event the match is part of
+    pub fn event(mut self, event: String) -> Self {
+        self.cricket_match.event = Some(event);
+        self
+    }
+
+    /// Sets the match's number within its format
+    pub fn match_number(mut self, match_number: i32) -> Self {
+        self.cricket_match.match_number = Some(match_number);
+        self
+    }
+
+    /// Sets the season for the match
+    pub fn season(mut self, season: String) -> Self {
+        self.cricket_match.season = Some(season);
+        self
+    }
+
+    /// Sets the overs allowed per innings
+    pub fn max_overs(mut self, max_overs: i32) -> Self {
+        self.cricket_match.max_overs = Some(max_overs);
+        self
+    }
+
+    /// Sets the legal deliveries per over
+    pub fn balls_per_over(mut self, balls_per_over: i32) -> Self {
+        self.cricket_match.balls_per_over = Some(balls_per_over);
+        self
+    }
+
+    /// Sets the players named player of the match
+    pub fn player_of_match(mut self, player_of_match: Vec<String>) -> Self {
+        self.cricket_match.player_of_match = player_of_match;
+        self
+    }
+
+    /// Sets whether it's a men's or women's match, e.g. "female"
+    pub fn gender(mut self, gender: String) -> Self {
+        self.cricket_match.gender = Some(gender);
+        self
+    }
+
+    /// Sets whether it's an international or club match
+    pub fn team_type(mut self, team_type: String) -> Self {
+        self.cricket_match.team_type = Some(team_type);
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> Match {
+        self.cricket_match
+    }
+}
+
+/// Quotes a CSV field if it contains a comma, quote or newline
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl MatchType {
+    /// Overs allowed per innings, or `None` for unlimited formats
+    #[must_use]
+    pub fn max_overs(&self) -> Option<i32> {
+        match self {
+            MatchType::Test => None,
+            MatchType::OD => Some(50),
+            MatchType::T20 => Some(20),
+            MatchType::Other(_) => None,
+        }
+    }
+}
+
+impl WinMargin {
+    /// Runs a wicket in hand is taken to be worth by `magnitude`
+    pub const RUNS_PER_WICKET: f64 = 10.0;
+
+    /// The size of the margin for sorting victories, e.g. to find the biggest
+    /// win. Runs count as they are and wickets as `RUNS_PER_WICKET` runs each,
+    /// while awards and broken ties have no margin and count as 0. Margins of the same kind
+    /// compare exactly, but comparing runs against wickets is only a rough
+    /// heuristic.
+    #[must_use]
+    pub fn magnitude(&self) -> f64 {
+        match self {
+            WinMargin::Runs(runs) => f64::from(*runs),
+            WinMargin::Wickets(wickets) => f64::from(*wickets) * Self::RUNS_PER_WICKET,
+            WinMargin::Award | WinMargin::Tiebreak(_) => 0.0,
+        }
+    }
+}
+
+impl FromStr for MatchType {
+    type Err = Infallible;
+
+    /// Parses a match type case-insensitively, accepting common aliases such as
+    /// "ODI" or "Twenty20". Unknown names become `MatchType::Other`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let match_type = match s.trim().to_lowercase().as_str() {
+            "test" | "test match" => MatchType::Test,
+            "od" | "odi" | "one day" | "one-day" | "one day international" => MatchType::OD,
+            "t20" | "t20i" | "twenty20" | "twenty 20" => MatchType::T20,
+            _ => MatchType::Other(s.to_string()),
+        };
+        Ok(match_type)
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Other is serialized as {"Other": "..."}, every other variant as its name
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum MatchTypeRepr {
+            Name(String),
+            Other {
+                #[serde(rename = "Other")]
+                other: String,
+            },
+        }
+
+        Ok(match MatchTypeRepr::deserialize(deserializer)? {
+            MatchTypeRepr::Name(name) => name.parse().unwrap_or_else(|e| match e {}),
+            MatchTypeRepr::Other { other } => MatchType::Other(other),
+        })
+    }
+}
+
+/// Reads match dates from a list, or from the single and possibly null date of
+/// older files
+fn deserialize_dates<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum DatesRepr {
+        One(Option<String>),
+        Many(Vec<String>),
+    }
+
+    Ok(match DatesRepr::deserialize(deserializer)? {
+        DatesRepr::One(date) => date.into_iter().collect(),
+        DatesRepr::Many(dates) => dates,
+    })
+}
+
+impl FromStr for ResultMethod {
+    type Err = Infallible;
+
+    /// Parses a result method case-insensitively, e.g. "D/L" or "DLS" as
+    /// `ResultMethod::DuckworthLewis`. Unknown methods become `ResultMethod::Other`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let method = match s.trim().to_lowercase().as_str() {
+            "d/l" | "dls" | "d/l/s" | "duckworth-lewis" | "duckworth lewis" => {
+                ResultMethod::DuckworthLewis
+            }
+            "vjd" => ResultMethod::Vjd,
+            "awarded" => ResultMethod::Awarded,
+            "forfeit" | "forfeited" => ResultMethod::Forfeit,
+            "rain" => ResultMethod::Rain,
+            "bad light" => ResultMethod::BadLight,
+            _ => ResultMethod::Other(s.to_string()),
+        };
+        Ok(method)
+    }
+}
+
+impl fmt::Display for ResultMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResultMethod::DuckworthLewis => write!(f, "D/L"),
+            ResultMethod::Vjd => write!(f, "VJD"),
+            ResultMethod::Awarded => write!(f, "Awarded"),
+            ResultMethod::Forfeit => write!(f, "forfeit"),
+            ResultMethod::Rain => write!(f, "rain"),
+            ResultMethod::BadLight => write!(f, "bad light"),
+            ResultMethod::Other(method) => write!(f, "{method}"),
+        }
+    }
+}
+
+impl Serialize for ResultMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResultMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let method = String::deserialize(deserializer)?;
+        Ok(method.parse().unwrap_or_else(|e| match e {}))
+    }
+}
+
+impl Default for MatchType {
+    fn default() -> Self {
+        MatchType::Other("Unknown".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ball::{BallEvents, BallOutcome};
+
+    fn create_test_team(name: &str) -> Team {
+        Team {
+            name: name.to_string(),
+            players: vec![
+                Player::new("Player1".to_string()),
+                Player::new("Player2".to_string()),
+            ],
+            ..Default::default()
+        }
+    }
+
+    fn create_test_innings(batting_team: Team, bowling_team: Team, runs: i32) -> Innings {
+        let mut innings = Innings::new(batting_team, bowling_team);
+        innings.score.runs = runs;
+        innings
     }
 
     #[test]
@@ -459,7 +1519,7 @@ mod tests {
         assert_eq!(match_instance.id, "M001");
         assert_eq!(match_instance.title, "Test Match");
         assert!(match_instance.venue.is_none());
-        assert!(match_instance.date.is_none());
+        assert!(match_instance.first_date().is_none());
         assert!(matches!(match_instance.match_type, MatchType::Test));
         assert_eq!(match_instance.team1.name, team1.name);
         assert_eq!(match_instance.team2.name, team2.name);
@@ -487,7 +1547,82 @@ mod tests {
             match_instance.venue,
             Some("Lord's Cricket Ground".to_string())
         );
-        assert_eq!(match_instance.date, Some("2025-01-01".to_string()));
+        assert_eq!(match_instance.first_date(), Some(&"2025-01-01".to_string()));
+    }
+
+    #[test]
+    fn test_match_builder() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+
+        let match_instance = Match::builder(
+            "M003".to_string(),
+            "T10 Match".to_string(),
+            MatchType::Other("T10".to_string()),
+            team1,
+            team2,
+        )
+        .venue("Sharjah".to_string())
+        .home_team("Team B".to_string())
+        .dates(vec!["2025-02-01".to_string(), "2025-02-02".to_string()])
+        .event("Winter League".to_string())
+        .match_number(7)
+        .season("2024/25".to_string())
+        .max_overs(10)
+        .balls_per_over(5)
+        .player_of_match(vec!["Player1".to_string()])
+        .gender("female".to_string())
+        .team_type("club".to_string())
+        .build();
+
+        assert_eq!(match_instance.id, "M003");
+        assert_eq!(match_instance.venue, Some("Sharjah".to_string()));
+        assert_eq!(match_instance.home_team, Some("Team B".to_string()));
+        assert!(match_instance.is_multi_day());
+        assert_eq!(match_instance.event, Some("Winter League".to_string()));
+        assert_eq!(match_instance.match_number, Some(7));
+        assert_eq!(match_instance.season, Some("2024/25".to_string()));
+        assert_eq!(match_instance.max_overs, Some(10));
+        assert_eq!(match_instance.balls_per_over, Some(5));
+        assert_eq!(match_instance.player_of_match, vec!["Player1".to_string()]);
+        assert_eq!(match_instance.gender, Some("female".to_string()));
+        assert_eq!(match_instance.team_type, Some("club".to_string()));
+
+        let with_event = Match::new(
+            "M004".to_string(),
+            "T20 Match".to_string(),
+            MatchType::T20,
+            create_test_team("Team A"),
+            create_test_team("Team B"),
+        )
+        .with_event("Cup".to_string());
+        assert_eq!(with_event.event, Some("Cup".to_string()));
+    }
+
+    #[test]
+    fn test_teams_in_display_order() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let match_instance = Match::new(
+            "M002".to_string(),
+            "ODI Match".to_string(),
+            MatchType::OD,
+            team1,
+            team2,
+        );
+
+        let (first, second) = match_instance.teams_in_display_order();
+        assert_eq!(
+            (first.name.as_str(), second.name.as_str()),
+            ("Team A", "Team B")
+        );
+
+        let match_instance = match_instance.with_home_team("Team B".to_string());
+        let (first, second) = match_instance.teams_in_display_order();
+        assert_eq!(
+            (first.name.as_str(), second.name.as_str()),
+            ("Team B", "Team A")
+        );
     }
 
     #[test]
@@ -513,6 +1648,31 @@ mod tests {
         assert_eq!(match_instance.innings[1].score.runs, 145);
     }
 
+    #[test]
+    fn test_add_innings_numbers_and_configures_innings() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut match_instance = Match::builder(
+            "M003".to_string(),
+            "T10 Match".to_string(),
+            MatchType::Other("T10".to_string()),
+            team1.clone(),
+            team2.clone(),
+        )
+        .max_overs(10)
+        .build();
+
+        match_instance.add_innings(create_test_innings(team1.clone(), team2.clone(), 120));
+        match_instance.add_innings(create_test_innings(team2, team1, 100));
+
+        assert_eq!(match_instance.innings[0].innings_number, 1);
+        assert_eq!(match_instance.innings[1].innings_number, 2);
+        assert_eq!(match_instance.innings[0].max_overs, Some(10));
+        assert_eq!(match_instance.innings[1].max_overs, Some(10));
+        assert_eq!(match_instance.innings[1].target, Some(121));
+        assert!(!match_instance.is_in_progress());
+    }
+
     #[test]
     fn test_set_status() {
         let team1 = create_test_team("Team A");
@@ -663,17 +1823,21 @@ mod tests {
         // Set an awarded result (e.g., due to forfeit)
         let result = MatchResult::Team1Won {
             margin: WinMargin::Award,
-            method: Some("forfeit".to_string()),
+            method: Some(ResultMethod::Forfeit),
         };
         match_instance.set_result(result);
 
         assert!(match_instance.is_completed());
+        assert_eq!(
+            match_instance.result_summary(),
+            "Team A awarded the match (forfeit)"
+        );
         match match_instance.result.unwrap() {
             MatchResult::Team1Won {
                 margin: WinMargin::Award,
                 method: Some(method_str),
             } => {
-                assert_eq!(method_str, "forfeit");
+                assert_eq!(method_str, ResultMethod::Forfeit);
             }
             _ => panic!("Expected Team1Won by award"),
         }
@@ -732,6 +1896,41 @@ mod tests {
         assert_eq!(match_instance.team2.name, cloned_match.team2.name);
     }
 
+    #[test]
+    fn test_verify_result() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut match_instance = Match::new(
+            "M009".to_string(),
+            "Test Match".to_string(),
+            MatchType::OD,
+            team1.clone(),
+            team2.clone(),
+        );
+        match_instance.add_innings(create_test_innings(team1.clone(), team2.clone(), 200));
+        let mut innings2 = create_test_innings(team2, team1, 150);
+        innings2.score.wickets_left = 0;
+        match_instance.add_innings(innings2);
+
+        match_instance.calculate_result();
+        assert_eq!(match_instance.verify_result(), Ok(()));
+
+        let wrong = MatchResult::Team2Won {
+            margin: WinMargin::Wickets(3),
+            method: None,
+        };
+        match_instance.set_result(wrong.clone());
+        let mismatch = match_instance.verify_result().unwrap_err();
+        assert_eq!(mismatch.stored, Some(wrong));
+        assert_eq!(
+            mismatch.calculated,
+            Some(MatchResult::Team1Won {
+                margin: WinMargin::Runs(50),
+                method: None,
+            })
+        );
+    }
+
     #[test]
     fn test_calculate_result_simple_win_by_runs() {
         let team1 = create_test_team("Team A");
@@ -826,6 +2025,41 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_calculate_result_tied_test() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut match_instance = Match::new(
+            "M011".to_string(),
+            "Brisbane 1960".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        );
+
+        // 453 and 284 against 505 and 232 all out
+        match_instance.add_innings(create_test_innings(team1.clone(), team2.clone(), 453));
+        match_instance.add_innings(create_test_innings(team2.clone(), team1.clone(), 505));
+        match_instance.add_innings(create_test_innings(team1.clone(), team2.clone(), 284));
+        let mut innings4 = create_test_innings(team2, team1, 232);
+        innings4.score.wickets_lost = 10;
+        innings4.score.wickets_left = 0;
+        match_instance.add_innings(innings4);
+
+        match_instance.calculate_result();
+        assert!(matches!(
+            match_instance.result,
+            Some(MatchResult::Tie { method: None })
+        ));
+
+        // Level with wickets in hand when time runs out is a draw in a Test
+        let innings4 = match_instance.innings.last_mut().unwrap();
+        innings4.score.wickets_lost = 9;
+        innings4.score.wickets_left = 1;
+        match_instance.calculate_result();
+        assert!(matches!(match_instance.result, Some(MatchResult::Draw)));
+    }
+
     #[test]
     fn test_calculate_result_draw() {
         let team1 = create_test_team("Team A");
@@ -868,9 +2102,9 @@ mod tests {
         // Test setting result with Duckworth-Lewis method
         let result = MatchResult::Team1Won {
             margin: WinMargin::Runs(15),
-            method: Some("D/L".to_string()),
+            method: Some(ResultMethod::DuckworthLewis),
         };
-        match_instance.set_result_with_method(result, Some("D/L".to_string()));
+        match_instance.set_result_with_method(result, Some(ResultMethod::DuckworthLewis));
 
         assert!(match_instance.is_completed());
         match match_instance.result.unwrap() {
@@ -879,7 +2113,7 @@ mod tests {
                 method: Some(method_str),
             } => {
                 assert_eq!(runs, 15);
-                assert_eq!(method_str, "D/L");
+                assert_eq!(method_str, ResultMethod::DuckworthLewis);
             }
             _ => panic!("Expected Team1Won with method"),
         }
@@ -889,7 +2123,7 @@ mod tests {
     fn test_match_result_with_method_serialization() {
         let result_with_method = MatchResult::Team2Won {
             margin: WinMargin::Wickets(3),
-            method: Some("VJD".to_string()),
+            method: Some(ResultMethod::Vjd),
         };
 
         let json = serde_json::to_string(&result_with_method).unwrap();
@@ -901,10 +2135,31 @@ mod tests {
                 method: Some(method_str),
             } => {
                 assert_eq!(wickets, 3);
-                assert_eq!(method_str, "VJD");
+                assert_eq!(method_str, ResultMethod::Vjd);
             }
             _ => panic!("Expected Team2Won with method"),
         }
+        assert!(json.contains(r#""method":"VJD""#));
+    }
+
+    #[test]
+    fn test_result_method_from_str() {
+        let parse = |s: &str| s.parse::<ResultMethod>().unwrap();
+        assert_eq!(parse("D/L"), ResultMethod::DuckworthLewis);
+        assert_eq!(parse("DLS"), ResultMethod::DuckworthLewis);
+        assert_eq!(parse("VJD"), ResultMethod::Vjd);
+        assert_eq!(parse("Awarded"), ResultMethod::Awarded);
+        assert_eq!(parse("forfeit"), ResultMethod::Forfeit);
+        assert_eq!(parse("bad light"), ResultMethod::BadLight);
+        assert_eq!(
+            parse("Lost fewer wickets"),
+            ResultMethod::Other("Lost fewer wickets".to_string())
+        );
+        assert_eq!(ResultMethod::DuckworthLewis.to_string(), "D/L");
+        assert_eq!(
+            ResultMethod::Other("Lost fewer wickets".to_string()).to_string(),
+            "Lost fewer wickets"
+        );
     }
 
     #[test]
@@ -933,4 +2188,960 @@ mod tests {
 
         assert!(match_instance.is_innings_victory());
     }
+
+    #[test]
+    fn test_start_innings_sets_chase_target() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut match_instance = Match::new(
+            "M014".to_string(),
+            "T20 Match".to_string(),
+            MatchType::T20,
+            team1.clone(),
+            team2.clone(),
+        );
+
+        let first = match_instance.start_innings(team1.clone(), team2.clone());
+        assert_eq!(first.innings_number, 1);
+        assert_eq!(first.target, None);
+        first.score.runs = 165;
+        assert!(match_instance.is_in_progress());
+
+        let second = match_instance.start_innings(team2, team1);
+        assert_eq!(second.innings_number, 2);
+        assert_eq!(second.target, Some(166));
+        assert_eq!(match_instance.innings.len(), 2);
+    }
+
+    #[test]
+    fn test_start_innings_test_match_target_in_fourth_innings() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut match_instance = Match::new(
+            "M015".to_string(),
+            "Test Match".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        );
+
+        match_instance
+            .start_innings(team1.clone(), team2.clone())
+            .score
+            .runs = 300;
+        let second = match_instance.start_innings(team2.clone(), team1.clone());
+        assert_eq!(second.target, None);
+        second.score.runs = 250;
+        match_instance
+            .start_innings(team1.clone(), team2.clone())
+            .score
+            .runs = 200;
+
+        // Team B needs 300 + 200 - 250 + 1
+        let fourth = match_instance.start_innings(team2, team1);
+        assert_eq!(fourth.innings_number, 4);
+        assert_eq!(fourth.target, Some(251));
+    }
+
+    #[test]
+    fn test_extras_conceded_by() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut match_instance = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        );
+        assert_eq!(match_instance.most_extras_conceding_team(), None);
+
+        let with_extras = |batting: &Team, bowling: &Team, wides, no_balls, byes| {
+            let mut innings = create_test_innings(batting.clone(), bowling.clone(), 200);
+            innings.score.wides = wides;
+            innings.score.no_balls = no_balls;
+            innings.score.byes = byes;
+            innings
+        };
+        match_instance.add_innings(with_extras(&team1, &team2, 3, 1, 4));
+        match_instance.add_innings(with_extras(&team2, &team1, 5, 0, 0));
+        match_instance.add_innings(with_extras(&team1, &team2, 2, 2, 1));
+
+        let extras = match_instance.extras_conceded_by("Team B");
+        assert_eq!(extras.wides, 5);
+        assert_eq!(extras.no_balls, 3);
+        assert_eq!(extras.byes, 5);
+        assert_eq!(extras.total, 13);
+        assert_eq!(match_instance.extras_conceded_by("Team A").total, 5);
+        assert_eq!(
+            match_instance.extras_conceded_by("Team C"),
+            Extras::default()
+        );
+        assert_eq!(
+            match_instance.most_extras_conceding_team(),
+            Some("Team B".to_string())
+        );
+    }
+
+    #[test]
+    fn test_all_rounders() {
+        let team = |name: &str, players: [&str; 2]| Team {
+            name: name.to_string(),
+            players: players
+                .map(|player| Player::new(player.to_string()))
+                .to_vec(),
+            ..Default::default()
+        };
+        let team1 = team("Team A", ["Batter", "All Rounder"]);
+        let team2 = team("Team B", ["Opener", "Bowler"]);
+        let mut match_instance = Match::new(
+            "M001".to_string(),
+            "T20 Match".to_string(),
+            MatchType::T20,
+            team1.clone(),
+            team2.clone(),
+        );
+        let ball = |runs, batting: &Team, striker: usize, bowler: &Player| {
+            BallOutcome::new(
+                runs,
+                vec![],
+                batting.players[striker].clone(),
+                batting.players[1 - striker].clone(),
+                bowler.clone(),
+            )
+        };
+
+        let innings1 = match_instance.start_innings(team1.clone(), team2.clone());
+        innings1.score_ball(&ball(2, &team1, 0, &team2.players[1]));
+        innings1.score_ball(&ball(1, &team1, 1, &team2.players[1]));
+        let innings2 = match_instance.start_innings(team2.clone(), team1.clone());
+        innings2.score_ball(&ball(0, &team2, 0, &team1.players[1]));
+        innings2.score_ball(&ball(4, &team2, 0, &team1.players[1]));
+
+        let all_rounders = match_instance.all_rounders();
+        assert_eq!(all_rounders.len(), 1);
+        assert_eq!(all_rounders[0].name, "All Rounder");
+        assert_eq!(all_rounders[0].runs, 1);
+        assert_eq!(all_rounders[0].balls_faced, 1);
+        assert_eq!(all_rounders[0].balls_bowled, 2);
+        assert_eq!(all_rounders[0].runs_conceded, 4);
+
+        let totals = match_instance.player_totals();
+        let batter = totals
+            .iter()
+            .find(|player| player.name == "Batter")
+            .unwrap();
+        assert_eq!(batter.balls_faced, 1);
+        assert_eq!(batter.balls_bowled, 0);
+    }
+
+    #[test]
+    fn test_total_boundaries_and_dot_ball_percentage() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut match_instance = Match::new(
+            "M016".to_string(),
+            "T20 Match".to_string(),
+            MatchType::T20,
+            team1.clone(),
+            team2.clone(),
+        );
+
+        let ball = |runs, events, batting: &Team, bowling: &Team| {
+            BallOutcome::new(
+                runs,
+                events,
+                batting.players[0].clone(),
+                batting.players[1].clone(),
+                bowling.players[0].clone(),
+            )
+        };
+
+        // Team A: a four, a six and two dots
+        let innings1 = match_instance.start_innings(team1.clone(), team2.clone());
+        innings1.score_ball(&ball(4, vec![BallEvents::Four], &team1, &team2));
+        innings1.score_ball(&ball(6, vec![BallEvents::Six], &team1, &team2));
+        innings1.score_ball(&ball(0, vec![], &team1, &team2));
+        innings1.score_ball(&ball(0, vec![], &team1, &team2));
+
+        // Team B: two fours and a dot
+        let innings2 = match_instance.start_innings(team2.clone(), team1.clone());
+        innings2.score_ball(&ball(4, vec![BallEvents::Four], &team2, &team1));
+        innings2.score_ball(&ball(4, vec![BallEvents::Four], &team2, &team1));
+        innings2.score_ball(&ball(0, vec![], &team2, &team1));
+
+        assert_eq!(match_instance.total_boundaries(), (3, 1));
+        // 3 dots from 7 legal balls
+        let dot_percentage = match_instance.dot_ball_percentage();
+        assert!((dot_percentage - 300.0 / 7.0).abs() < 0.000001);
+    }
+
+    #[test]
+    fn test_dot_ball_percentage_no_balls() {
+        let match_instance = Match::new(
+            "M017".to_string(),
+            "T20 Match".to_string(),
+            MatchType::T20,
+            create_test_team("Team A"),
+            create_test_team("Team B"),
+        );
+        assert_eq!(match_instance.total_boundaries(), (0, 0));
+        assert_eq!(match_instance.dot_ball_percentage(), 0.0);
+    }
+
+    #[test]
+    fn test_ordering_key() {
+        let new_match = |id: &str, season: &str, date: &str| {
+            Match::new(
+                id.to_string(),
+                "ODI Match".to_string(),
+                MatchType::OD,
+                create_test_team("Team A"),
+                create_test_team("Team B"),
+            )
+            .with_season(season.to_string())
+            .with_date(date.to_string())
+            .with_match_number(4000)
+        };
+
+        let mut matches = [
+            new_match("third", "2024", "2024-03-01"),
+            new_match("first", "2023/24", "2023-12-26"),
+            new_match("second", "2023/24", "2024-01-03"),
+        ];
+        assert_eq!(
+            matches[0].ordering_key(),
+            (Some("2024".to_string()), Some("2024-03-01".to_string()))
+        );
+        assert_eq!(matches[0].match_number, Some(4000));
+
+        matches.sort_by_key(Match::ordering_key);
+        let ids: Vec<&str> = matches.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn test_result_summary_with_forfeited_innings() {
+        let england = create_test_team("England");
+        let south_africa = create_test_team("South Africa");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "South Africa vs England".to_string(),
+            MatchType::Test,
+            england.clone(),
+            south_africa.clone(),
+        );
+
+        let mut innings1 = create_test_innings(south_africa.clone(), england.clone(), 248);
+        innings1.score.wickets_lost = 8;
+        innings1.score.wickets_left = 2;
+        innings1.declared = true;
+        cricket_match.add_innings(innings1);
+
+        let mut innings2 = create_test_innings(england.clone(), south_africa.clone(), 0);
+        innings2.forfeited = true;
+        cricket_match.add_innings(innings2);
+
+        let mut innings3 = create_test_innings(south_africa.clone(), england.clone(), 0);
+        innings3.forfeited = true;
+        cricket_match.add_innings(innings3);
+
+        let mut innings4 = create_test_innings(england, south_africa, 251);
+        innings4.score.wickets_lost = 8;
+        innings4.score.wickets_left = 2;
+        cricket_match.add_innings(innings4);
+
+        cricket_match.calculate_result();
+
+        assert!(!cricket_match.is_innings_victory());
+        assert_eq!(cricket_match.result_summary(), "England won by 2 wickets");
+        assert_eq!(cricket_match.innings[0].scoreboard(), "8/248 declared");
+        assert_eq!(cricket_match.innings[1].scoreboard(), "forfeited");
+    }
+
+    #[test]
+    fn test_bowling_side_forfeit_awards_batting_side() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        );
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 300));
+        let mut innings2 = create_test_innings(team2, team1, 0);
+        innings2.forfeited = true;
+        innings2.forfeited_by = ForfeitBy::Bowling;
+        cricket_match.add_innings(innings2);
+
+        cricket_match.calculate_result();
+
+        assert!(matches!(
+            cricket_match.result,
+            Some(MatchResult::Team2Won {
+                margin: WinMargin::Award,
+                ..
+            })
+        ));
+        assert_eq!(cricket_match.winner(), Some("Team B"));
+        assert_eq!(
+            cricket_match.result_summary(),
+            "Team B awarded the match (forfeit)"
+        );
+    }
+
+    #[test]
+    fn test_result_summary_runs_and_draw() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::OD,
+            team1,
+            team2,
+        );
+        assert_eq!(cricket_match.result_summary(), "Match in progress");
+
+        cricket_match.set_result_with_method(
+            MatchResult::Team2Won {
+                margin: WinMargin::Runs(1),
+                method: None,
+            },
+            Some(ResultMethod::DuckworthLewis),
+        );
+        assert_eq!(cricket_match.result_summary(), "Team B won by 1 run (D/L)");
+
+        cricket_match.set_result(MatchResult::Draw);
+        assert_eq!(cricket_match.result_summary(), "Match drawn");
+    }
+
+    fn create_tied_t20(team1: &Team, team2: &Team) -> Match {
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Tied T20".to_string(),
+            MatchType::T20,
+            team1.clone(),
+            team2.clone(),
+        );
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 160));
+        let mut chase = create_test_innings(team2.clone(), team1.clone(), 160);
+        chase.score.wickets_left = 3;
+        cricket_match.add_innings(chase);
+        cricket_match
+    }
+
+    #[test]
+    fn test_calculate_result_repeated_super_overs() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = create_tied_t20(&team1, &team2);
+
+        // Two tied super overs, then Team B wins the third
+        for (team2_runs, team1_runs) in [(12, 12), (9, 9)] {
+            cricket_match.add_super_over(create_test_innings(
+                team2.clone(),
+                team1.clone(),
+                team2_runs,
+            ));
+            cricket_match.add_super_over(create_test_innings(
+                team1.clone(),
+                team2.clone(),
+                team1_runs,
+            ));
+        }
+        cricket_match.add_super_over(create_test_innings(team2.clone(), team1.clone(), 15));
+        cricket_match.add_super_over(create_test_innings(team1.clone(), team2.clone(), 11));
+
+        cricket_match.calculate_result();
+
+        assert!(matches!(
+            cricket_match.result,
+            Some(MatchResult::Team2Won {
+                margin: WinMargin::Tiebreak(Tiebreak::SuperOver),
+                ..
+            })
+        ));
+        assert_eq!(cricket_match.tiebreak(), Some(&Tiebreak::SuperOver));
+        assert_eq!(
+            cricket_match.decision(),
+            Some(Decision::Tie(Some(Tiebreak::SuperOver)))
+        );
+        assert_eq!(cricket_match.result_summary(), "Team B won the super over");
+    }
+
+    #[test]
+    fn test_calculate_result_boundary_count_after_tied_super_overs() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = create_tied_t20(&team1, &team2);
+        cricket_match.innings[0].batting_team.players[0].fours = 10;
+        cricket_match.innings[1].batting_team.players[0].sixes = 8;
+
+        let mut super_over = create_test_innings(team2.clone(), team1.clone(), 15);
+        super_over.batting_team.players[0].sixes = 1;
+        cricket_match.add_super_over(super_over);
+        cricket_match.add_super_over(create_test_innings(team1.clone(), team2.clone(), 15));
+
+        cricket_match.calculate_result();
+
+        assert!(matches!(
+            cricket_match.result,
+            Some(MatchResult::Team1Won {
+                margin: WinMargin::Tiebreak(Tiebreak::BoundaryCount),
+                ..
+            })
+        ));
+        assert_eq!(cricket_match.tiebreak(), Some(&Tiebreak::BoundaryCount));
+        assert_eq!(
+            cricket_match.result_summary(),
+            "Team A won on boundary count"
+        );
+        assert!(cricket_match.ended_level());
+    }
+
+    #[test]
+    fn test_calculate_result_tie_without_super_overs() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = create_tied_t20(&team1, &team2);
+
+        cricket_match.calculate_result();
+
+        assert!(matches!(
+            cricket_match.result,
+            Some(MatchResult::Tie { .. })
+        ));
+        assert!(cricket_match.tiebreak().is_none());
+    }
+
+    #[test]
+    fn test_try_new_rejects_duplicate_team_names() {
+        let team = create_test_team("Team A");
+
+        let result = Match::try_new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::T20,
+            team.clone(),
+            team.clone(),
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            MatchCreationError::DuplicateTeamNames("Team A".to_string())
+        );
+
+        let result = Match::try_new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::T20,
+            team,
+            create_test_team("Team B"),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_match_type_from_str_aliases() {
+        assert!(matches!("ODI".parse::<MatchType>(), Ok(MatchType::OD)));
+        assert!(matches!(
+            "TWENTY20".parse::<MatchType>(),
+            Ok(MatchType::T20)
+        ));
+        assert!(matches!(
+            "Test match".parse::<MatchType>(),
+            Ok(MatchType::Test)
+        ));
+        assert!(
+            matches!("gully".parse::<MatchType>(), Ok(MatchType::Other(name)) if name == "gully")
+        );
+    }
+
+    #[test]
+    fn test_match_type_deserialize_aliases() {
+        let od: MatchType = serde_json::from_str("\"odi\"").unwrap();
+        assert!(matches!(od, MatchType::OD));
+        let t20: MatchType = serde_json::from_str("\"TWENTY20\"").unwrap();
+        assert!(matches!(t20, MatchType::T20));
+        let other: MatchType = serde_json::from_str("\"gully\"").unwrap();
+        assert!(matches!(other, MatchType::Other(name) if name == "gully"));
+
+        // Serialized values still round trip
+        for match_type in [MatchType::Test, MatchType::Other("Hundred".to_string())] {
+            let json = serde_json::to_string(&match_type).unwrap();
+            let parsed: MatchType = serde_json::from_str(&json).unwrap();
+            assert_eq!(format!("{parsed:?}"), format!("{match_type:?}"));
+        }
+    }
+
+    #[test]
+    fn test_set_dls_target_reduced_second_innings() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Rain affected ODI".to_string(),
+            MatchType::OD,
+            team1.clone(),
+            team2.clone(),
+        );
+
+        // 250 from the full 50 overs, then the chase is cut to 40 overs
+        let mut innings1 = create_test_innings(team1.clone(), team2.clone(), 250);
+        innings1.score.over = 50;
+        cricket_match.add_innings(innings1);
+        cricket_match.add_innings(create_test_innings(team2, team1, 0));
+
+        let resources_used = cricket_match.first_innings_resources_used().unwrap();
+        assert!((resources_used - 100.0).abs() < 1e-9);
+
+        let target = cricket_match.set_dls_target(40);
+        // Matches the published Standard Edition target for this case
+        assert_eq!(target, Some(224));
+        assert_eq!(cricket_match.innings[1].target, target);
+        assert_eq!(cricket_match.innings[1].max_overs, Some(40));
+        let situation = cricket_match.innings[1].match_situation().unwrap();
+        assert_eq!(
+            situation.runs_needed,
+            224 - cricket_match.innings[1].score.runs
+        );
+        assert_eq!(
+            situation.balls_remaining,
+            240 - cricket_match.innings[1].score.legal_balls()
+        );
+    }
+
+    #[test]
+    fn test_first_innings_resources_used_shortened_innings() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Rain affected ODI".to_string(),
+            MatchType::OD,
+            team1.clone(),
+            team2.clone(),
+        );
+        assert!(cricket_match.first_innings_resources_used().is_none());
+
+        // Innings ended by rain at 150/2 after 30 overs
+        let mut innings1 = create_test_innings(team1, team2, 150);
+        innings1.score.over = 30;
+        innings1.score.wickets_lost = 2;
+        cricket_match.add_innings(innings1);
+
+        let resources_used = cricket_match.first_innings_resources_used().unwrap();
+        let expected = 100.0 - dls::resources_remaining(20.0, 2);
+        assert!((resources_used - expected).abs() < 1e-9);
+        assert!(resources_used < 75.0);
+    }
+
+    #[test]
+    fn test_is_multi_day() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+
+        let test_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        )
+        .with_dates((10..15).map(|day| format!("2025-06-{day}")).collect());
+        assert!(test_match.is_multi_day());
+        assert_eq!(test_match.first_date(), Some(&"2025-06-10".to_string()));
+
+        let t20 = Match::new(
+            "M002".to_string(),
+            "T20".to_string(),
+            MatchType::T20,
+            team1,
+            team2,
+        )
+        .with_date("2025-06-20".to_string());
+        assert!(!t20.is_multi_day());
+    }
+
+    #[test]
+    fn test_decision() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::OD,
+            team1.clone(),
+            team2.clone(),
+        );
+        assert_eq!(cricket_match.decision(), None);
+
+        let won = |margin: WinMargin| MatchResult::Team1Won {
+            margin,
+            method: None,
+        };
+        for (result, decision) in [
+            (won(WinMargin::Runs(30)), Decision::ByRuns(30)),
+            (
+                MatchResult::Team2Won {
+                    margin: WinMargin::Wickets(4),
+                    method: Some(ResultMethod::DuckworthLewis),
+                },
+                Decision::ByWickets(4),
+            ),
+            (won(WinMargin::Award), Decision::Awarded),
+            (MatchResult::Tie { method: None }, Decision::Tie(None)),
+            (MatchResult::Draw, Decision::Draw),
+            (MatchResult::NoResult, Decision::Abandoned),
+        ] {
+            cricket_match.set_result(result);
+            assert_eq!(cricket_match.decision(), Some(decision));
+        }
+
+        cricket_match.result = None;
+        cricket_match.set_status(MatchStatus::Abandoned);
+        assert_eq!(cricket_match.decision(), Some(Decision::Abandoned));
+
+        cricket_match.set_result(won(WinMargin::Tiebreak(Tiebreak::SuperOver)));
+        assert_eq!(
+            cricket_match.decision(),
+            Some(Decision::Tie(Some(Tiebreak::SuperOver)))
+        );
+        assert_eq!(cricket_match.tiebreak(), Some(&Tiebreak::SuperOver));
+
+        // Awards are only ever decisions to award the match
+        cricket_match.set_result(won(WinMargin::Award));
+        assert_eq!(cricket_match.decision(), Some(Decision::Awarded));
+        assert!(cricket_match.tiebreak().is_none());
+    }
+
+    #[test]
+    fn test_decision_innings_victory() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        );
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 400));
+        let mut innings2 = create_test_innings(team2.clone(), team1.clone(), 150);
+        innings2.score.wickets_left = 0;
+        cricket_match.add_innings(innings2);
+        let mut innings3 = create_test_innings(team2, team1, 200);
+        innings3.score.wickets_left = 0;
+        cricket_match.add_innings(innings3);
+
+        cricket_match.calculate_result();
+
+        assert_eq!(
+            cricket_match.decision(),
+            Some(Decision::ByInningsAndRuns(50))
+        );
+    }
+
+    #[test]
+    fn test_closeness() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "ODI".to_string(),
+            MatchType::OD,
+            team1,
+            team2,
+        );
+        assert_eq!(cricket_match.closeness(), None);
+
+        let mut closeness = |result: MatchResult| {
+            cricket_match.set_result(result);
+            cricket_match.closeness()
+        };
+        let won = |margin: WinMargin| MatchResult::Team1Won {
+            margin,
+            method: None,
+        };
+        let tie = closeness(MatchResult::Tie { method: None }).unwrap();
+        let one_run = closeness(won(WinMargin::Runs(1))).unwrap();
+        let one_wicket = closeness(won(WinMargin::Wickets(1))).unwrap();
+        let hundred_runs = closeness(won(WinMargin::Runs(100))).unwrap();
+        let five_wickets = closeness(won(WinMargin::Wickets(5))).unwrap();
+
+        assert_eq!(tie, 0.0);
+        assert!(tie < one_run);
+        assert_eq!(one_run, one_wicket);
+        assert!(one_run < five_wickets);
+        assert!(one_run < hundred_runs);
+        assert_eq!(closeness(MatchResult::Draw), None);
+        assert_eq!(closeness(won(WinMargin::Award)), None);
+    }
+
+    #[test]
+    fn test_winner_and_loser() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::OD,
+            team1,
+            team2,
+        );
+        assert_eq!(cricket_match.winner(), None);
+        assert_eq!(cricket_match.loser(), None);
+
+        cricket_match.set_result(MatchResult::Team2Won {
+            margin: WinMargin::Wickets(4),
+            method: None,
+        });
+        assert_eq!(cricket_match.winner(), Some("Team B"));
+        assert_eq!(cricket_match.loser(), Some("Team A"));
+
+        cricket_match.set_result(MatchResult::Draw);
+        assert_eq!(cricket_match.winner(), None);
+        assert_eq!(cricket_match.loser(), None);
+    }
+
+    #[test]
+    fn test_ended_level() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::T20,
+            team1.clone(),
+            team2.clone(),
+        );
+        assert!(!cricket_match.ended_level());
+
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 160));
+        cricket_match.add_innings(create_test_innings(team2.clone(), team1.clone(), 159));
+        assert!(!cricket_match.ended_level());
+
+        assert!(create_tied_t20(&team1, &team2).ended_level());
+    }
+
+    #[test]
+    fn test_who_is_ahead_test_lead() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        );
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 350));
+        assert_eq!(cricket_match.who_is_ahead(), None);
+
+        cricket_match.add_innings(create_test_innings(team2.clone(), team1.clone(), 280));
+        assert_eq!(
+            cricket_match.who_is_ahead(),
+            Some(("Team A".to_string(), Ahead::Lead(70)))
+        );
+    }
+
+    #[test]
+    fn test_who_is_ahead_on_chase() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "T20 Match".to_string(),
+            MatchType::T20,
+            team1.clone(),
+            team2.clone(),
+        );
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 159));
+        // 100 from 10 overs needing 160: 10 an over against 6 an over required
+        let mut chase = create_test_innings(team2.clone(), team1.clone(), 100);
+        chase.target = Some(160);
+        chase.score.over = 10;
+        cricket_match.add_innings(chase);
+
+        let (leader, ahead) = cricket_match.who_is_ahead().unwrap();
+        assert_eq!(leader, "Team B");
+        let Ahead::OnChase {
+            run_rate,
+            required_run_rate,
+        } = ahead
+        else {
+            panic!("Expected a chase comparison, got {ahead:?}");
+        };
+        assert!((run_rate - 10.0).abs() < 1e-9);
+        assert!((required_run_rate - 6.0).abs() < 1e-9);
+
+        // Reduced to 15 overs after rain, the chase is judged against par
+        cricket_match.set_dls_target(15);
+        assert!(matches!(
+            cricket_match.who_is_ahead(),
+            Some((_, Ahead::OfPar(_)))
+        ));
+    }
+
+    #[test]
+    fn test_team_batting_runs() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        );
+        let mut first = create_test_innings(team1.clone(), team2.clone(), 300);
+        first.score.byes = 4;
+        first.score.penalties = 6;
+        cricket_match.add_innings(first);
+        cricket_match.add_innings(create_test_innings(team2.clone(), team1.clone(), 250));
+        let mut third = create_test_innings(team1.clone(), team2.clone(), 120);
+        third.score.wides = 2;
+        cricket_match.add_innings(third);
+
+        assert_eq!(cricket_match.team_batting_runs("Team A"), 408);
+        assert_eq!(cricket_match.team1_total_runs(), 420);
+        assert_eq!(cricket_match.team_batting_runs("Team B"), 250);
+        assert_eq!(cricket_match.team_batting_runs("Team C"), 0);
+    }
+
+    #[test]
+    fn test_innings_efficiency() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::Test,
+            team1.clone(),
+            team2.clone(),
+        );
+        // 300 off 90 overs against 150 off 25
+        let mut first = create_test_innings(team1.clone(), team2.clone(), 300);
+        first.score.over = 90;
+        cricket_match.add_innings(first);
+        let mut second = create_test_innings(team2.clone(), team1.clone(), 150);
+        second.score.over = 25;
+        cricket_match.add_innings(second);
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 0));
+
+        let efficiency = cricket_match.innings_efficiency();
+        assert_eq!(efficiency.len(), 2);
+        assert_eq!(efficiency[0], (1, 1.8));
+        assert_eq!(efficiency[1], (2, 1.0));
+        assert!(efficiency[1].1 < efficiency[0].1);
+    }
+
+    #[test]
+    fn test_win_margin_magnitude() {
+        assert!(WinMargin::Runs(100).magnitude() > WinMargin::Runs(10).magnitude());
+        assert!(WinMargin::Wickets(9).magnitude() > WinMargin::Wickets(2).magnitude());
+        assert!(WinMargin::Wickets(1).magnitude() > WinMargin::Award.magnitude());
+        assert_eq!(WinMargin::Award.magnitude(), 0.0);
+        assert_eq!(WinMargin::Tiebreak(Tiebreak::SuperOver).magnitude(), 0.0);
+
+        let mut margins = [
+            WinMargin::Wickets(3),
+            WinMargin::Runs(150),
+            WinMargin::Award,
+            WinMargin::Wickets(8),
+        ];
+        margins.sort_by(|a, b| b.magnitude().total_cmp(&a.magnitude()));
+        assert!(matches!(margins[0], WinMargin::Runs(150)));
+        assert!(matches!(margins[1], WinMargin::Wickets(8)));
+        assert!(matches!(margins[3], WinMargin::Award));
+    }
+
+    #[test]
+    fn test_to_summary_row() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "T20 Match".to_string(),
+            MatchType::T20,
+            team1.clone(),
+            team2.clone(),
+        )
+        .with_date("2024-01-01".to_string());
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 160));
+        cricket_match.add_innings(create_test_innings(team2.clone(), team1.clone(), 150));
+        cricket_match.set_result(MatchResult::Team1Won {
+            margin: WinMargin::Runs(10),
+            method: None,
+        });
+        cricket_match.player_of_match = vec!["Player1".to_string()];
+
+        let row = cricket_match.to_summary_row();
+        assert_eq!(
+            row.split(',').count(),
+            Match::SUMMARY_CSV_HEADER.split(',').count()
+        );
+        assert!(row.contains(&cricket_match.result_summary()));
+        assert_eq!(
+            row,
+            "2024-01-01,T20,Team A,Team B,160,150,Team A won by 10 runs,Player1"
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("Team A"), "Team A");
+        assert_eq!(csv_field("Lord's, London"), "\"Lord's, London\"");
+        assert_eq!(csv_field("the \"Ashes\""), "\"the \"\"Ashes\"\"\"");
+    }
+
+    #[test]
+    fn test_requires_super_over() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = create_tied_t20(&team1, &team2);
+        // The chase is still going with three wickets left
+        assert!(!cricket_match.requires_super_over());
+
+        cricket_match.innings[0].finished = true;
+        cricket_match.innings[1].finished = true;
+        assert!(cricket_match.requires_super_over());
+
+        cricket_match.match_type = MatchType::Test;
+        assert!(!cricket_match.requires_super_over());
+    }
+
+    #[test]
+    fn test_add_innings_sets_chase_target() {
+        let team1 = create_test_team("Team A");
+        let team2 = create_test_team("Team B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "OD Match".to_string(),
+            MatchType::OD,
+            team1.clone(),
+            team2.clone(),
+        );
+        cricket_match.add_innings(create_test_innings(team1.clone(), team2.clone(), 275));
+        cricket_match.add_innings(create_test_innings(team2.clone(), team1.clone(), 0));
+
+        assert_eq!(cricket_match.innings[0].target, None);
+        assert_eq!(cricket_match.innings[1].target, Some(276));
+
+        // A target that's already set, e.g. a revised one, is kept
+        let mut revised = Match::new(
+            "M002".to_string(),
+            "OD Match".to_string(),
+            MatchType::OD,
+            team1.clone(),
+            team2.clone(),
+        );
+        revised.add_innings(create_test_innings(team1.clone(), team2.clone(), 275));
+        let mut chase = create_test_innings(team2, team1, 0);
+        chase.target = Some(230);
+        revised.add_innings(chase);
+        assert_eq!(revised.innings[1].target, Some(230));
+    }
 }
@@ -1,12 +1,21 @@
 pub mod ball;
+pub mod dls;
 pub mod innings;
 pub mod r#match;
+pub mod notation;
+pub mod over;
 pub mod player;
 pub mod score;
+pub mod scorecard;
 
 // Re-export commonly used types
-pub use ball::{BallEvents, BallOutcome, Wicket};
-pub use innings::Innings;
+pub use ball::{BallEvents, BallOutcome, BallRecord, DismissalKind, ReviewRecord, Wicket};
+pub use innings::{Dismissal, ForfeitBy, FowEntry, Innings, MatchSituation, Matchup, Partnership};
+pub use over::{BallNumber, Over, OverSummary, Phase};
 pub use player::{Player, Team};
-pub use r#match::{Match, MatchResult, MatchStatus, MatchType, WinMargin};
-pub use score::CurrentScore;
+pub use r#match::{
+    Ahead, Decision, Match, MatchBuilder, MatchResult, MatchStatus, MatchType, ResultMethod,
+    Tiebreak, WinMargin,
+};
+pub use score::{CurrentScore, RunSources};
+pub use scorecard::Scorecard;
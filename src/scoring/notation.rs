@@ -0,0 +1,172 @@
+use crate::error::BallString;
+
+use super::{
+    ball::{BallEvents, BallOutcome, Wicket},
+    player::Player,
+};
+
+/// Characters other than digits that can appear in a ball's notation
+const ALLOWED_CHARS: [char; 8] = ['.', 'W', 'X', 'B', 'L', 'O', 'F', 'S'];
+
+/// Parses a single delivery written in compact notation.
+///
+/// The notation is an optional number of runs (digits, or `.` for none) followed
+/// by any of these events:
+///
+/// - `W`: the striker is out
+/// - `X`: a wide, where `nX` means n wides in total (`X` alone is one wide)
+/// - `O`: a no ball, with any runs scored off it
+/// - `B` / `L`: the runs were byes / leg byes, so a number must be given
+/// - `F` / `S`: the runs were a four / six, which default to 4 / 6 runs
+///
+/// For example `1` is a single, `.` a dot ball, `W` a wicket, `2L` two leg byes,
+/// `F` a four and `WX` a stumping off a wide.
+///
+/// # Errors
+///
+/// Will return an error if the notation is empty, contains an unknown character,
+/// has byes or leg byes without a number of runs, or has both a four and a six
+/// or both byes and leg byes
+pub fn parse(
+    ball: &str,
+    on_strike: &Player,
+    off_strike: &Player,
+    bowler: &Player,
+) -> Result<BallOutcome, BallString> {
+    if ball.is_empty() {
+        return Err(BallString::EmptyBallString);
+    }
+    if let Some(c) = ball
+        .chars()
+        .find(|c| !(c.is_ascii_digit() || ALLOWED_CHARS.contains(c)))
+    {
+        return Err(BallString::InvalidBallStringCharacter(c));
+    }
+
+    let digits: String = ball.chars().take_while(char::is_ascii_digit).collect();
+    if (ball.contains('B') || ball.contains('L')) && digits.is_empty() {
+        // A bye/leg bye must include the number of runs scored
+        return Err(BallString::InvalidByeCharacter);
+    }
+    if (ball.contains('F') && ball.contains('S')) || (ball.contains('B') && ball.contains('L')) {
+        // cannot have both a four and a six, or a bye and a leg bye
+        return Err(BallString::InvalidBallDescription);
+    }
+
+    let runs = match digits.parse::<i32>() {
+        Ok(runs) => runs,
+        Err(_) if ball.contains('F') => 4,
+        Err(_) if ball.contains('S') => 6,
+        Err(_) => 0,
+    };
+
+    let mut ball_events = vec![];
+    if ball.contains('W') {
+        ball_events.push(BallEvents::Wicket(vec![Wicket {
+            player_out: on_strike.name.clone(),
+            kind: "unknown".to_string(),
+            ..Default::default()
+        }]));
+    }
+    if ball.contains('O') {
+        ball_events.push(BallEvents::NoBall(1));
+    }
+    if ball.contains('F') {
+        ball_events.push(BallEvents::Four);
+    }
+    if ball.contains('S') {
+        ball_events.push(BallEvents::Six);
+    }
+
+    // Wides, byes and leg byes aren't runs off the bat
+    let runs = if ball.contains('X') {
+        ball_events.push(BallEvents::Wide(runs.max(1)));
+        0
+    } else if ball.contains('B') {
+        ball_events.push(BallEvents::Bye(runs));
+        0
+    } else if ball.contains('L') {
+        ball_events.push(BallEvents::LegBye(runs));
+        0
+    } else {
+        runs
+    };
+
+    Ok(BallOutcome::new(
+        runs,
+        ball_events,
+        on_strike.clone(),
+        off_strike.clone(),
+        bowler.clone(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_ball(ball: &str) -> Result<BallOutcome, BallString> {
+        parse(
+            ball,
+            &Player::new("Striker".to_string()),
+            &Player::new("NonStriker".to_string()),
+            &Player::new("Bowler".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_parse_runs() {
+        assert_eq!(parse_ball("3").unwrap().runs, 3);
+        assert_eq!(parse_ball(".").unwrap().runs, 0);
+
+        let four = parse_ball("F").unwrap();
+        assert_eq!(four.runs, 4);
+        assert!(four.four);
+        let six = parse_ball("S").unwrap();
+        assert_eq!(six.runs, 6);
+        assert!(six.six);
+    }
+
+    #[test]
+    fn test_parse_extras() {
+        let wide = parse_ball("X").unwrap();
+        assert_eq!(wide.wide, Some(1));
+        assert_eq!(wide.runs, 0);
+        assert_eq!(parse_ball("3X").unwrap().wide, Some(3));
+
+        let leg_byes = parse_ball("2L").unwrap();
+        assert_eq!(leg_byes.leg_byes, Some(2));
+        assert_eq!(leg_byes.runs, 0);
+        assert_eq!(parse_ball("4BF").unwrap().byes, Some(4));
+
+        let no_ball = parse_ball("2O").unwrap();
+        assert_eq!(no_ball.no_ball, Some(1));
+        assert_eq!(no_ball.runs, 2);
+    }
+
+    #[test]
+    fn test_parse_wicket() {
+        let stumped = parse_ball("WX").unwrap();
+        assert_eq!(stumped.wide, Some(1));
+        let wickets = stumped.wicket.unwrap();
+        assert_eq!(wickets.len(), 1);
+        assert_eq!(wickets[0].player_out, "Striker");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(parse_ball(""), Err(BallString::EmptyBallString)));
+        assert!(matches!(
+            parse_ball("1Z"),
+            Err(BallString::InvalidBallStringCharacter('Z'))
+        ));
+        assert!(matches!(
+            parse_ball("L"),
+            Err(BallString::InvalidByeCharacter)
+        ));
+        assert!(matches!(
+            parse_ball("4FS"),
+            Err(BallString::InvalidBallDescription)
+        ));
+    }
+}
@@ -0,0 +1,317 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::ball::BallOutcome;
+use crate::error::ParseOversError;
+
+/// A point in an innings in overs and balls, written "10.3" for the third ball
+/// of the eleventh over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Over {
+    /// Completed overs
+    pub over: i32,
+    /// Balls into the current over
+    pub ball: i32,
+}
+
+impl Over {
+    #[must_use]
+    pub fn new(over: i32, ball: i32) -> Over {
+        Over { over, ball }
+    }
+
+    /// Converts a number of legal balls into overs and balls
+    #[must_use]
+    pub fn from_balls(balls: i32, balls_per_over: i32) -> Over {
+        Over {
+            over: balls / balls_per_over,
+            ball: balls % balls_per_over,
+        }
+    }
+
+    /// The number of legal balls up to this point
+    #[must_use]
+    pub fn to_balls(&self, balls_per_over: i32) -> i32 {
+        self.over * balls_per_over + self.ball
+    }
+
+    /// Parses overs written as "10.3" (ten overs and three balls) or "10"
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string isn't in that format or the ball count
+    /// isn't less than `balls_per_over`
+    pub fn parse(s: &str, balls_per_over: i32) -> Result<Over, ParseOversError> {
+        let invalid = || ParseOversError::InvalidFormat(s.to_string());
+        let (over, ball) = s.trim().split_once('.').unwrap_or((s.trim(), "0"));
+        let over: i32 = over.parse().map_err(|_| invalid())?;
+        let ball: i32 = ball.parse().map_err(|_| invalid())?;
+        if over < 0 || ball < 0 {
+            return Err(invalid());
+        }
+        if ball >= balls_per_over {
+            return Err(ParseOversError::BallOutOfRange {
+                ball,
+                balls_per_over,
+            });
+        }
+        Ok(Over { over, ball })
+    }
+}
+
+/// Converts overs written as "10.3" into a number of legal balls
+///
+/// # Errors
+///
+/// Will return an error if the overs can't be parsed (see `Over::parse`)
+pub fn overs_to_balls(s: &str, balls_per_over: i32) -> Result<i32, ParseOversError> {
+    Ok(Over::parse(s, balls_per_over)?.to_balls(balls_per_over))
+}
+
+impl fmt::Display for Over {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.over, self.ball)
+    }
+}
+
+/// A delivery's number as written on a scorecard, e.g. "10.3" for the third ball
+/// of the eleventh over. Unlike `Over`, which counts the balls already bowled,
+/// the ball counts from 1, so the last ball of a six ball over is "10.6".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BallNumber {
+    /// Completed overs before the delivery
+    pub over: i32,
+    /// The delivery's place in its over, from 1
+    pub ball: i32,
+}
+
+impl BallNumber {
+    #[must_use]
+    pub fn new(over: i32, ball: i32) -> BallNumber {
+        BallNumber { over, ball }
+    }
+}
+
+impl fmt::Display for BallNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.over, self.ball)
+    }
+}
+
+/// The phases of a limited overs innings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Phase {
+    /// The opening overs with fielding restrictions
+    Powerplay,
+    Middle,
+    /// The closing overs
+    Death,
+}
+
+impl Phase {
+    /// The phase an over (0-indexed) falls in for an innings of `max_overs`. The
+    /// powerplay is the first 30% of overs and the death the last quarter, each
+    /// at most 10 overs, giving 6 and 5 overs in a T20 and 10 of each in an ODI.
+    #[must_use]
+    pub fn of(over: i32, max_overs: i32) -> Phase {
+        let powerplay_overs = ((max_overs * 3 + 9) / 10).min(10);
+        let death_overs = (max_overs / 4).min(10);
+        if over < powerplay_overs {
+            Phase::Powerplay
+        } else if over >= max_overs - death_overs {
+            Phase::Death
+        } else {
+            Phase::Middle
+        }
+    }
+}
+
+/// What happened in a single over of an innings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverSummary {
+    /// The over number (0-indexed)
+    pub over: i32,
+    /// The bowler who started the over
+    pub bowler: String,
+    /// Runs added to the total in the over, including extras
+    pub runs: i32,
+    /// Runs charged to the bowler, i.e. excluding byes, leg byes and penalties
+    pub bowler_runs: i32,
+    /// Wickets that fell in the over
+    pub wickets: i32,
+    /// Wickets credited to the bowler
+    pub bowler_wickets: i32,
+    /// Legal deliveries bowled in the over
+    pub legal_balls: i32,
+    /// Every delivery bowled in the over, including wides and no balls
+    pub deliveries: i32,
+}
+
+impl OverSummary {
+    #[must_use]
+    pub fn new(over: i32, bowler: String) -> OverSummary {
+        OverSummary {
+            over,
+            bowler,
+            runs: 0,
+            bowler_runs: 0,
+            wickets: 0,
+            bowler_wickets: 0,
+            legal_balls: 0,
+            deliveries: 0,
+        }
+    }
+
+    /// Adds a delivery to the over's figures
+    pub fn add_delivery(&mut self, ball_outcome: &BallOutcome) {
+        if ball_outcome.dead_ball {
+            return;
+        }
+        if !ball_outcome.is_timed_out() {
+            self.deliveries += 1;
+        }
+        if ball_outcome.is_legal() {
+            self.legal_balls += 1;
+        }
+        self.runs += ball_outcome.total_runs();
+        self.bowler_runs += ball_outcome.bowler_runs();
+        for wicket in ball_outcome.wicket.iter().flatten() {
+            if wicket.dismissal_kind().is_wicket() {
+                self.wickets += 1;
+            }
+            if wicket.credited_to_bowler() {
+                self.bowler_wickets += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ball::{BallEvents, Wicket};
+    use crate::scoring::player::Player;
+
+    fn create_test_ball_outcome(runs: i32, events: Vec<BallEvents>) -> BallOutcome {
+        BallOutcome::new(
+            runs,
+            events,
+            Player::new("Striker".to_string()),
+            Player::new("NonStriker".to_string()),
+            Player::new("Bowler".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_over_balls_conversion() {
+        let over = Over::from_balls(63, 6);
+        assert_eq!(over, Over::new(10, 3));
+        assert_eq!(over.to_balls(6), 63);
+        assert_eq!(over.to_string(), "10.3");
+        assert!(Over::new(9, 5) < Over::new(10, 0));
+    }
+
+    #[test]
+    fn test_overs_to_balls() {
+        assert_eq!(overs_to_balls("10.3", 6), Ok(63));
+        assert_eq!(overs_to_balls("20", 6), Ok(120));
+        assert_eq!(overs_to_balls("10.4", 5), Ok(54));
+        assert!(matches!(
+            overs_to_balls("ten", 6),
+            Err(ParseOversError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_overs_to_balls_ball_out_of_range() {
+        assert_eq!(
+            overs_to_balls("10.7", 6),
+            Err(ParseOversError::BallOutOfRange {
+                ball: 7,
+                balls_per_over: 6
+            })
+        );
+        assert_eq!(
+            overs_to_balls("10.5", 5),
+            Err(ParseOversError::BallOutOfRange {
+                ball: 5,
+                balls_per_over: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_phase_of() {
+        assert_eq!(Phase::of(5, 20), Phase::Powerplay);
+        assert_eq!(Phase::of(6, 20), Phase::Middle);
+        assert_eq!(Phase::of(14, 20), Phase::Middle);
+        assert_eq!(Phase::of(15, 20), Phase::Death);
+        assert_eq!(Phase::of(9, 50), Phase::Powerplay);
+        assert_eq!(Phase::of(39, 50), Phase::Middle);
+        assert_eq!(Phase::of(40, 50), Phase::Death);
+    }
+
+    #[test]
+    fn test_over_summary_new() {
+        let summary = OverSummary::new(3, "Bowler".to_string());
+        assert_eq!(summary.over, 3);
+        assert_eq!(summary.bowler, "Bowler");
+        assert_eq!(summary.runs, 0);
+        assert_eq!(summary.deliveries, 0);
+    }
+
+    #[test]
+    fn test_over_summary_add_delivery() {
+        let mut summary = OverSummary::new(0, "Bowler".to_string());
+        let run_out = Wicket {
+            player_out: "Striker".to_string(),
+            kind: "run out".to_string(),
+            ..Default::default()
+        };
+        let bowled = Wicket {
+            player_out: "Striker".to_string(),
+            kind: "bowled".to_string(),
+            ..Default::default()
+        };
+
+        summary.add_delivery(&create_test_ball_outcome(4, vec![BallEvents::Four]));
+        summary.add_delivery(&create_test_ball_outcome(0, vec![BallEvents::Wide(1)]));
+        summary.add_delivery(&create_test_ball_outcome(0, vec![BallEvents::LegBye(2)]));
+        summary.add_delivery(&create_test_ball_outcome(
+            1,
+            vec![BallEvents::Wicket(vec![run_out])],
+        ));
+        summary.add_delivery(&create_test_ball_outcome(
+            0,
+            vec![BallEvents::Wicket(vec![bowled])],
+        ));
+
+        assert_eq!(summary.runs, 8);
+        assert_eq!(summary.bowler_runs, 6);
+        assert_eq!(summary.wickets, 2);
+        assert_eq!(summary.bowler_wickets, 1);
+        assert_eq!(summary.legal_balls, 4);
+        assert_eq!(summary.deliveries, 5);
+    }
+
+    #[test]
+    fn test_over_summary_retirement_is_not_a_wicket() {
+        let mut summary = OverSummary::new(0, "Bowler".to_string());
+        let retired_hurt = Wicket {
+            player_out: "Striker".to_string(),
+            kind: "retired hurt".to_string(),
+            ..Default::default()
+        };
+
+        summary.add_delivery(&create_test_ball_outcome(1, vec![]));
+        summary.add_delivery(&create_test_ball_outcome(
+            0,
+            vec![BallEvents::Wicket(vec![retired_hurt])],
+        ));
+        summary.add_delivery(&create_test_ball_outcome(2, vec![]));
+
+        assert_eq!(summary.wickets, 0);
+        assert_eq!(summary.bowler_wickets, 0);
+        assert_eq!(summary.legal_balls, 3);
+    }
+}
@@ -21,6 +21,11 @@ use serde::{Deserialize, Serialize};
 /// * `maidens` - Number of maiden overs bowled
 /// * `wides` - Number of wides bowled
 /// * `no_balls` - Number of no balls bowled
+/// * `dot_balls` - Number of legal deliveries bowled that conceded no runs to the bowler
+///
+/// ## Selection
+/// * `super_sub` - Whether the player is the team's super-sub, who isn't picked to bat
+///   unless substituted in
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub name: String,
@@ -40,15 +45,30 @@ pub struct Player {
     pub maidens: i32,
     pub wides: i32,
     pub no_balls: i32,
+    #[serde(default)]
+    pub dot_balls: i32,
+    #[serde(default)]
+    pub super_sub: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Team {
     pub players: Vec<Player>,
     pub name: String,
+    /// Reviews (DRS) the team has asked for
+    #[serde(default)]
+    pub reviews_used: i32,
+    /// Reviews that overturned the umpire's decision
+    #[serde(default)]
+    pub reviews_successful: i32,
 }
 
 impl Player {
+    /// Balls a batter must have faced to count as set by `is_set`
+    pub const SET_MIN_BALLS: i32 = 15;
+    /// Strike rate a batter must be scoring at to count as set by `is_set`
+    pub const SET_MIN_STRIKE_RATE: f64 = 60.0;
+
     #[must_use]
     pub fn new(name: String) -> Self {
         Player {
@@ -102,6 +122,47 @@ impl Player {
         }
     }
 
+    /// Calculate the number of balls the player faced per boundary hit
+    /// Returns None if the player has not hit a boundary
+    #[must_use]
+    pub fn balls_per_boundary(&self) -> Option<f64> {
+        let boundaries = self.fours + self.sixes;
+        if boundaries == 0 {
+            None
+        } else {
+            Some(self.balls_faced as f64 / boundaries as f64)
+        }
+    }
+
+    /// Calculate the percentage of the player's legal deliveries that were dot balls
+    /// Returns None if the player has not bowled any balls
+    #[must_use]
+    pub fn dot_ball_percentage(&self) -> Option<f64> {
+        if self.balls_bowled == 0 {
+            None
+        } else {
+            Some(self.dot_balls as f64 / self.balls_bowled as f64 * 100.0)
+        }
+    }
+
+    /// Whether the batter is well set, a rough heuristic for commentary: not out,
+    /// having faced at least `SET_MIN_BALLS` balls at a strike rate of at least
+    /// `SET_MIN_STRIKE_RATE`
+    #[must_use]
+    pub fn is_set(&self) -> bool {
+        self.is_set_with(Self::SET_MIN_BALLS, Self::SET_MIN_STRIKE_RATE)
+    }
+
+    /// As `is_set`, with the minimum balls faced and strike rate given
+    #[must_use]
+    pub fn is_set_with(&self, min_balls: i32, min_strike_rate: f64) -> bool {
+        !self.out
+            && self.balls_faced >= min_balls
+            && self
+                .strike_rate()
+                .is_some_and(|strike_rate| strike_rate >= min_strike_rate)
+    }
+
     /// Get the number of complete overs bowled
     #[must_use]
     pub fn overs_bowled(&self) -> (i32, i32) {
@@ -109,6 +170,24 @@ impl Player {
         let remaining_balls = self.balls_bowled % 6;
         (complete_overs, remaining_balls)
     }
+
+    /// The player's name as a key for matching names across sources, see
+    /// `normalize_name`
+    #[must_use]
+    pub fn normalized_name(&self) -> String {
+        normalize_name(&self.name)
+    }
+}
+
+/// A key for comparing names regardless of case and spacing, so "cj bowes" and
+/// "CJ  Bowes " are both "cj bowes". Only use it to compare names, as it doesn't
+/// keep their capitalisation.
+#[must_use]
+pub fn normalize_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
 }
 
 impl Team {
@@ -119,6 +198,45 @@ impl Team {
     pub fn get_player(&mut self, player_name: &str) -> Option<&mut Player> {
         self.players.iter_mut().find(|p| p.name == player_name)
     }
+
+    /// Zeroes every player's batting and bowling stats, keeping the roster
+    pub fn reset_stats(&mut self) {
+        for player in &mut self.players {
+            *player = Player {
+                super_sub: player.super_sub,
+                ..Player::new(std::mem::take(&mut player.name))
+            };
+        }
+        self.reviews_used = 0;
+        self.reviews_successful = 0;
+    }
+
+    /// Index of the next batter due in after position `after` in the batting order,
+    /// skipping a super-sub who hasn't been substituted in. The index is past the
+    /// end of the team once nobody is left to bat.
+    #[must_use]
+    pub fn next_batter_index(&self, after: usize) -> usize {
+        (after + 1..self.players.len())
+            .find(|&index| !self.players[index].super_sub)
+            .unwrap_or(self.players.len().max(after + 1))
+    }
+
+    /// Indices of the two opening batters, skipping a super-sub who hasn't been
+    /// substituted in
+    #[must_use]
+    pub fn opening_pair(&self) -> (usize, usize) {
+        let on_strike = (0..self.players.len())
+            .find(|&index| !self.players[index].super_sub)
+            .unwrap_or(0);
+        (on_strike, self.next_batter_index(on_strike))
+    }
+
+    /// Brings the team's super-sub into the side so they can bat
+    pub fn substitute_super_sub(&mut self) {
+        for player in &mut self.players {
+            player.super_sub = false;
+        }
+    }
 }
 
 impl fmt::Display for Player {
@@ -136,10 +254,21 @@ impl fmt::Display for Player {
                 Some(sr) => format!(", SR: {:.2}", sr),
                 None => String::new(),
             };
+            // Balls per boundary is only shown with the alternate format, `{:#}`
+            let balls_per_boundary_str = match self.balls_per_boundary() {
+                Some(bpb) if f.alternate() => format!(", BpB: {:.1}", bpb),
+                _ => String::new(),
+            };
 
             parts.push(format!(
-                "Batting: {}{}({}), {} 4s, {} 6s{}",
-                self.runs, not_out_ind, self.balls_faced, self.fours, self.sixes, strike_rate_str
+                "Batting: {}{}({}), {} 4s, {} 6s{}{}",
+                self.runs,
+                not_out_ind,
+                self.balls_faced,
+                self.fours,
+                self.sixes,
+                strike_rate_str,
+                balls_per_boundary_str
             ));
         }
 
@@ -156,16 +285,21 @@ impl fmt::Display for Player {
                 Some(econ) => format!(", Econ: {:.2}", econ),
                 None => String::new(),
             };
+            let dot_ball_str = match self.dot_ball_percentage() {
+                Some(dots) => format!(", Dots: {:.1}%", dots),
+                None => String::new(),
+            };
 
             parts.push(format!(
-                "Bowling: {}-{} ({} overs), {} maidens, {} wides, {} no balls{}",
+                "Bowling: {}-{} ({} overs), {} maidens, {} wides, {} no balls{}{}",
                 self.wickets_taken,
                 self.runs_conceded,
                 overs_str,
                 self.maidens,
                 self.wides,
                 self.no_balls,
-                economy_str
+                economy_str,
+                dot_ball_str
             ));
         }
 
@@ -276,6 +410,7 @@ mod tests {
         let team = Team {
             name: "Test Team".to_string(),
             players: players.clone(),
+            ..Default::default()
         };
 
         assert_eq!(team.name, "Test Team");
@@ -295,6 +430,7 @@ mod tests {
         let team = Team {
             name: "Original Team".to_string(),
             players,
+            ..Default::default()
         };
 
         let cloned_team = team.clone();
@@ -304,11 +440,82 @@ mod tests {
         assert_eq!(team.players[1].name, cloned_team.players[1].name);
     }
 
+    #[test]
+    fn test_team_reset_stats() {
+        let mut batter = Player::new("Batter".to_string());
+        batter.runs = 40;
+        batter.balls_faced = 25;
+        batter.out = true;
+        batter.dismissal = Some("bowled".to_string());
+        let mut bowler = Player::new("Bowler".to_string());
+        bowler.balls_bowled = 24;
+        bowler.wickets_taken = 2;
+
+        let mut team = Team {
+            name: "Test Team".to_string(),
+            players: vec![batter, bowler],
+            ..Default::default()
+        };
+        team.reset_stats();
+
+        assert_eq!(team.name, "Test Team");
+        assert_eq!(team.players.len(), 2);
+        assert_eq!(team.players[0].name, "Batter");
+        assert_eq!(team.players[0].runs, 0);
+        assert_eq!(team.players[0].balls_faced, 0);
+        assert!(!team.players[0].out);
+        assert_eq!(team.players[0].dismissal, None);
+        assert_eq!(team.players[1].name, "Bowler");
+        assert_eq!(team.players[1].balls_bowled, 0);
+        assert_eq!(team.players[1].wickets_taken, 0);
+    }
+
+    #[test]
+    fn test_next_batter_index_skips_super_sub() {
+        let mut super_sub = Player::new("Sub".to_string());
+        super_sub.super_sub = true;
+        let mut team = Team {
+            name: "Test Team".to_string(),
+            players: vec![
+                Player::new("Opener1".to_string()),
+                Player::new("Opener2".to_string()),
+                super_sub,
+                Player::new("Number3".to_string()),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(team.next_batter_index(1), 3);
+        assert_eq!(team.next_batter_index(3), 4);
+
+        team.substitute_super_sub();
+        assert_eq!(team.next_batter_index(1), 2);
+    }
+
+    #[test]
+    fn test_opening_pair_skips_super_sub() {
+        let mut super_sub = Player::new("Sub".to_string());
+        super_sub.super_sub = true;
+        let team = Team {
+            name: "Test Team".to_string(),
+            players: vec![
+                super_sub,
+                Player::new("Opener1".to_string()),
+                Player::new("Opener2".to_string()),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(team.opening_pair(), (1, 2));
+        assert_eq!(Team::default().opening_pair(), (0, 1));
+    }
+
     #[test]
     fn test_team_empty_players() {
         let team = Team {
             name: "Empty Team".to_string(),
             players: vec![],
+            ..Default::default()
         };
 
         assert_eq!(team.name, "Empty Team");
@@ -446,6 +653,7 @@ mod tests {
         assert_eq!(player.maidens, 0);
         assert_eq!(player.wides, 0);
         assert_eq!(player.no_balls, 0);
+        assert_eq!(player.dot_balls, 0);
     }
 
     #[test]
@@ -681,4 +889,86 @@ mod tests {
         player.runs_conceded = 0;
         assert_eq!(player.economy_rate(), Some(0.0));
     }
+
+    #[test]
+    fn test_normalized_name() {
+        assert_eq!(normalize_name("cj bowes"), "cj bowes");
+        assert_eq!(normalize_name("CJ  Bowes "), "cj bowes");
+        assert_eq!(
+            Player::new("cj bowes".to_string()).normalized_name(),
+            Player::new("CJ  Bowes".to_string()).normalized_name()
+        );
+        assert_eq!(normalize_name(""), "");
+
+        // Particles and apostrophes are compared as written, whatever the case
+        for (a, b) in [
+            ("AB de Villiers", " ab DE  villiers"),
+            ("MS Dhoni", "ms dhoni"),
+            ("SNJ O'Keefe", "snj o'keefe"),
+            ("HE van der Dussen", "HE  Van Der Dussen"),
+        ] {
+            assert_eq!(normalize_name(a), normalize_name(b));
+        }
+        assert_eq!(normalize_name("AB de Villiers"), "ab de villiers");
+        assert_ne!(normalize_name("SNJ O'Keefe"), normalize_name("SNJ OKeefe"));
+        assert_ne!(
+            normalize_name("HE van der Dussen"),
+            normalize_name("HE vanderDussen")
+        );
+    }
+
+    #[test]
+    fn test_dot_ball_percentage() {
+        let mut player = Player::new("Miserly Bowler".to_string());
+        assert_eq!(player.dot_ball_percentage(), None);
+
+        player.balls_bowled = 24;
+        player.dot_balls = 12;
+        player.runs_conceded = 20;
+        assert_eq!(player.dot_ball_percentage(), Some(50.0));
+        assert!(player.to_string().contains("Econ: 5.00, Dots: 50.0%"));
+    }
+
+    #[test]
+    fn test_balls_per_boundary() {
+        let mut player = Player::new("Big Hitter".to_string());
+        player.balls_faced = 40;
+        player.runs = 48;
+        player.fours = 6;
+        player.sixes = 2;
+        assert_eq!(player.balls_per_boundary(), Some(5.0));
+        assert!(format!("{player:#}").contains("SR: 120.00, BpB: 5.0"));
+        assert!(!player.to_string().contains("BpB"));
+
+        let mut nudger = Player::new("Nudger".to_string());
+        nudger.balls_faced = 40;
+        nudger.runs = 20;
+        assert_eq!(nudger.balls_per_boundary(), None);
+        assert!(!format!("{nudger:#}").contains("BpB"));
+    }
+
+    #[test]
+    fn test_is_set() {
+        let batter = |balls_faced: i32, runs: i32| Player {
+            balls_faced,
+            runs,
+            ..Player::new("Batter".to_string())
+        };
+
+        let set = batter(30, 25);
+        assert!(set.is_set());
+        assert!(!Player {
+            out: true,
+            ..set.clone()
+        }
+        .is_set());
+
+        let new_batter = batter(3, 4);
+        assert!(!new_batter.is_set());
+        assert!(new_batter.is_set_with(2, 100.0));
+
+        let bogged_down = batter(40, 12);
+        assert!(!bogged_down.is_set());
+        assert!(bogged_down.is_set_with(15, 25.0));
+    }
 }
@@ -3,7 +3,7 @@ use std::fmt;
 
 pub use super::ball::BallOutcome;
 
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CurrentScore {
     pub wickets_left: i32,
     pub wickets_lost: i32,
@@ -14,6 +14,58 @@ pub struct CurrentScore {
     pub no_balls: i32,
     pub over: i32,
     pub ball: i32,
+    /// Legal deliveries that make up an over (6 in all current formats)
+    #[serde(default = "default_balls_per_over")]
+    pub balls_per_over: i32,
+    /// Number of wides bowled, as opposed to `wides` which counts runs
+    #[serde(default)]
+    pub wides_bowled: i32,
+    /// Number of no balls bowled, as opposed to `no_balls` which counts runs
+    #[serde(default)]
+    pub no_balls_bowled: i32,
+    /// Penalty runs awarded to the batting side
+    #[serde(default)]
+    pub penalties: i32,
+    /// The part of `penalties` awarded outside of a delivery, e.g. for a slow
+    /// over rate
+    #[serde(default)]
+    pub innings_penalties: i32,
+}
+
+/// Where an innings' runs came from. The fields sum to the innings total.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSources {
+    pub off_bat: i32,
+    pub wides: i32,
+    pub no_balls: i32,
+    pub byes: i32,
+    pub leg_byes: i32,
+    pub penalties: i32,
+}
+
+fn default_balls_per_over() -> i32 {
+    6
+}
+
+impl Default for CurrentScore {
+    fn default() -> Self {
+        CurrentScore {
+            wickets_left: 0,
+            wickets_lost: 0,
+            runs: 0,
+            leg_byes: 0,
+            byes: 0,
+            wides: 0,
+            no_balls: 0,
+            over: 0,
+            ball: 0,
+            balls_per_over: default_balls_per_over(),
+            wides_bowled: 0,
+            no_balls_bowled: 0,
+            penalties: 0,
+            innings_penalties: 0,
+        }
+    }
 }
 
 impl CurrentScore {
@@ -26,56 +78,122 @@ impl CurrentScore {
     }
 
     pub fn score_ball(&mut self, ball_outcome: &BallOutcome) {
-        if ball_outcome.wide.is_none() && ball_outcome.no_ball.is_none() {
+        if ball_outcome.dead_ball {
+            return;
+        }
+        if ball_outcome.is_legal() {
             self.ball += 1;
         }
         self.runs += ball_outcome.runs;
-        if ball_outcome.wicket.is_some() {
-            for wicket in ball_outcome.wicket.clone().unwrap() {
-                if wicket.kind == "retired out" || !wicket.kind.contains("retired") {
+        if let Some(wickets) = &ball_outcome.wicket {
+            for wicket in wickets {
+                // A dismissal when already all out can only be bad data
+                if wicket.dismissal_kind().is_wicket() && self.wickets_left > 0 {
                     self.wickets_lost += 1;
                     self.wickets_left -= 1;
                 }
             }
         }
-        if ball_outcome.wide.is_some() {
-            self.wides += ball_outcome.wide.unwrap() + ball_outcome.runs;
-            self.runs += ball_outcome.wide.unwrap();
+        if let Some(wide) = ball_outcome.wide {
+            self.wides_bowled += 1;
+            self.wides += wide + ball_outcome.runs;
+            self.runs += wide;
         }
-        if ball_outcome.no_ball.is_some() {
-            self.no_balls += ball_outcome.no_ball.unwrap();
-            self.runs += ball_outcome.no_ball.unwrap();
+        if let Some(no_ball) = ball_outcome.no_ball {
+            self.no_balls_bowled += 1;
+            self.no_balls += no_ball;
+            self.runs += no_ball;
         }
-        if ball_outcome.byes.is_some() {
-            self.byes += ball_outcome.byes.unwrap();
-            self.runs += ball_outcome.byes.unwrap();
+        // Anything run off a wide counts as wides, as cricsheet records it
+        if let Some(byes) = ball_outcome.byes {
+            if ball_outcome.wide.is_some() {
+                self.wides += byes;
+            } else {
+                self.byes += byes;
+            }
+            self.runs += byes;
+        }
+        if let Some(leg_byes) = ball_outcome.leg_byes {
+            if ball_outcome.wide.is_some() {
+                self.wides += leg_byes;
+            } else {
+                self.leg_byes += leg_byes;
+            }
+            self.runs += leg_byes;
         }
-        if ball_outcome.leg_byes.is_some() {
-            self.leg_byes += ball_outcome.leg_byes.unwrap();
-            self.runs += ball_outcome.leg_byes.unwrap();
+        if let Some(overthrows) = ball_outcome.overthrows {
+            if ball_outcome.wide.is_some() {
+                self.wides += overthrows;
+            } else if ball_outcome.byes.is_some() {
+                self.byes += overthrows;
+            } else if ball_outcome.leg_byes.is_some() {
+                self.leg_byes += overthrows;
+            }
+            self.runs += overthrows;
         }
-        if ball_outcome.penalty.is_some() {
-            self.runs += ball_outcome.penalty.unwrap();
+        if let Some(penalty) = ball_outcome.penalty {
+            self.penalties += penalty;
+            self.runs += penalty;
         }
     }
 
+    /// Adds penalty runs awarded to the batting side other than on a delivery.
+    /// Like any penalty runs they count towards the total but aren't charged to
+    /// a bowler.
+    pub fn add_innings_penalty(&mut self, runs: i32) {
+        self.runs += runs;
+        self.penalties += runs;
+        self.innings_penalties += runs;
+    }
+
     pub fn over(&mut self) {
         self.over += 1;
         self.ball = 0;
     }
 
+    /// Number of legal balls bowled so far
+    #[must_use]
+    pub fn legal_balls(&self) -> i32 {
+        self.over * self.balls_per_over + self.ball
+    }
+
+    /// Number of deliveries bowled so far, including wides and no balls
+    #[must_use]
+    pub fn total_deliveries(&self) -> i32 {
+        self.legal_balls() + self.wides_bowled + self.no_balls_bowled
+    }
+
+    /// Splits the total into runs off the bat and each kind of extra
+    #[must_use]
+    pub fn run_sources(&self) -> RunSources {
+        let extras = self.wides + self.no_balls + self.byes + self.leg_byes + self.penalties;
+        RunSources {
+            off_bat: self.runs - extras,
+            wides: self.wides,
+            no_balls: self.no_balls,
+            byes: self.byes,
+            leg_byes: self.leg_byes,
+            penalties: self.penalties,
+        }
+    }
+
+    /// The score as it would appear on a scoreboard, in wickets/runs order
+    #[must_use]
+    pub fn scoreboard(&self) -> String {
+        format!("{}/{}", self.wickets_lost, self.runs)
+    }
+
     #[must_use]
     pub fn summary(&self) -> String {
+        format!("{}\n{}", self.scoreboard(), self.extras_and_overs())
+    }
+
+    /// The summary without its score line: the extras and the overs faced
+    #[must_use]
+    pub fn extras_and_overs(&self) -> String {
         format!(
-            "{}/{}\n{} wides, {} no balls, {} byes, {} leg byes\n{}.{}",
-            self.wickets_lost,
-            self.runs,
-            self.wides,
-            self.no_balls,
-            self.byes,
-            self.leg_byes,
-            self.over,
-            self.ball,
+            "{} wides, {} no balls, {} byes, {} leg byes\n{}.{}",
+            self.wides, self.no_balls, self.byes, self.leg_byes, self.over, self.ball,
         )
     }
 }
@@ -93,12 +211,13 @@ impl fmt::Display for CurrentScore {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::scoring::ball::Wicket;
+    use crate::scoring::ball::{DismissalKind, Wicket};
 
     fn create_test_wicket(kind: &str) -> Wicket {
         Wicket {
             player_out: "Test Player".to_string(),
             kind: kind.to_string(),
+            ..Default::default()
         }
     }
 
@@ -205,6 +324,54 @@ mod tests {
         assert_eq!(score.ball, 0); // Wide doesn't advance ball count
     }
 
+    #[test]
+    fn test_score_ball_wide_with_byes_matches_cricsheet() {
+        // Cricsheet records a wide the batters ran two on as
+        // {"extras": {"wides": 3}, "runs": {"batter": 0, "extras": 3, "total": 3}}
+        let mut score = CurrentScore::new();
+        let ball_outcome = BallOutcome {
+            runs: 0,
+            wide: Some(1),
+            byes: Some(2),
+            ..create_test_ball_outcome()
+        };
+
+        score.score_ball(&ball_outcome);
+
+        assert_eq!(score.runs, 3);
+        assert_eq!(score.runs, ball_outcome.total_runs());
+        assert_eq!(score.wides, 3);
+        assert_eq!(score.byes, 0);
+        assert_eq!(score.run_sources().off_bat, 0);
+        assert_eq!(ball_outcome.bowler_runs(), 3);
+    }
+
+    #[test]
+    fn test_score_ball_no_ball_with_runs_matches_cricsheet() {
+        // {"extras": {"noballs": 1}, "runs": {"batter": 2, "extras": 1, "total": 3}}
+        // and a no ball the batters ran two byes off, {"noballs": 1, "byes": 2}
+        let mut score = CurrentScore::new();
+        let hit = BallOutcome {
+            runs: 2,
+            no_ball: Some(1),
+            ..create_test_ball_outcome()
+        };
+        let byes = BallOutcome {
+            runs: 0,
+            no_ball: Some(1),
+            byes: Some(2),
+            ..create_test_ball_outcome()
+        };
+
+        score.score_ball(&hit);
+        assert_eq!(score.runs, 3);
+        score.score_ball(&byes);
+        assert_eq!(score.runs, 6);
+        assert_eq!(score.no_balls, 2);
+        assert_eq!(score.byes, 2);
+        assert_eq!(score.run_sources().off_bat, 2);
+    }
+
     #[test]
     fn test_score_ball_with_no_ball() {
         let mut score = CurrentScore::new();
@@ -268,6 +435,27 @@ mod tests {
         assert_eq!(score.ball, 1);
     }
 
+    #[test]
+    fn test_delivery_and_innings_penalties() {
+        let mut score = CurrentScore::new();
+        let ball_outcome = BallOutcome {
+            runs: 1,
+            penalty: Some(5),
+            ..create_test_ball_outcome()
+        };
+
+        score.add_innings_penalty(5);
+        score.score_ball(&ball_outcome);
+        score.add_innings_penalty(5);
+
+        assert_eq!(score.runs, 16);
+        assert_eq!(score.penalties, 15);
+        assert_eq!(score.innings_penalties, 10);
+        assert_eq!(score.penalties - score.innings_penalties, 5);
+        assert_eq!(score.run_sources().off_bat, 1);
+        assert_eq!(ball_outcome.bowler_runs(), 1);
+    }
+
     #[test]
     fn test_score_ball_complex() {
         let mut score = CurrentScore::new();
@@ -320,6 +508,39 @@ mod tests {
         assert_eq!(score.wickets_left, 9);
     }
 
+    #[test]
+    fn test_score_ball_wickets_left_never_negative() {
+        let mut score = CurrentScore::new();
+        for _ in 0..11 {
+            score.score_ball(&BallOutcome {
+                wicket: Some(vec![create_test_wicket("bowled")]),
+                ..create_test_ball_outcome()
+            });
+        }
+
+        assert_eq!(score.wickets_left, 0);
+        assert_eq!(score.wickets_lost, 10);
+        assert_eq!(score.ball, 11);
+    }
+
+    #[test]
+    fn test_score_ball_other_retirement() {
+        let mut score = CurrentScore::new();
+        let wicket = create_test_wicket("retired other");
+        assert_eq!(
+            wicket.dismissal_kind(),
+            DismissalKind::Other("retired other".to_string())
+        );
+
+        score.score_ball(&BallOutcome {
+            wicket: Some(vec![wicket]),
+            ..create_test_ball_outcome()
+        });
+
+        assert_eq!(score.wickets_lost, 0);
+        assert_eq!(score.wickets_left, 10);
+    }
+
     #[test]
     fn test_over() {
         let mut score = CurrentScore::new();
@@ -362,6 +583,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_legal_balls_and_total_deliveries() {
+        let mut score = CurrentScore::new();
+        score.over = 10;
+        score.ball = 3;
+        assert_eq!(score.legal_balls(), 63);
+        assert_eq!(score.total_deliveries(), 63);
+
+        let wide = BallOutcome {
+            wide: Some(1),
+            ..create_test_ball_outcome()
+        };
+        let no_ball = BallOutcome {
+            no_ball: Some(1),
+            ..create_test_ball_outcome()
+        };
+        score.score_ball(&wide);
+        score.score_ball(&no_ball);
+        assert_eq!(score.legal_balls(), 63);
+        assert_eq!(score.total_deliveries(), 65);
+
+        score.balls_per_over = 8;
+        assert_eq!(score.legal_balls(), 83);
+    }
+
+    #[test]
+    fn test_scoreboard() {
+        let mut score = CurrentScore::new();
+        score.wickets_lost = 6;
+        score.runs = 280;
+        assert_eq!(score.scoreboard(), "6/280");
+    }
+
     #[test]
     fn test_display() {
         let mut score = CurrentScore::new();
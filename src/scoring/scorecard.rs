@@ -0,0 +1,313 @@
+//! A structured scorecard for serializing, e.g. as JSON for a web page.
+//!
+//! The layout here is kept stable and independent of how `Match` and `Innings`
+//! store their state, so consumers can rely on it.
+
+use serde::Serialize;
+
+use super::{innings::Innings, over::Over, r#match::Match, score::RunSources};
+
+/// The scorecard of every innings in a match, in order
+#[derive(Debug, Clone, Serialize)]
+pub struct Scorecard {
+    pub innings: Vec<InningsScorecard>,
+}
+
+/// The scorecard of a single innings
+#[derive(Debug, Clone, Serialize)]
+pub struct InningsScorecard {
+    pub batting_team: String,
+    pub bowling_team: String,
+    /// The batters who batted, in batting order
+    pub batting: Vec<BattingLine>,
+    /// The bowlers who bowled, in the order they first bowled
+    pub bowling: Vec<BowlingLine>,
+    pub extras: Extras,
+    pub total: i32,
+    pub wickets: i32,
+    /// Overs faced, e.g. "19.4"
+    pub overs: String,
+    pub fall_of_wickets: Vec<FallOfWicket>,
+    pub declared: bool,
+    pub forfeited: bool,
+}
+
+/// A batter's line on the scorecard
+#[derive(Debug, Clone, Serialize)]
+pub struct BattingLine {
+    pub name: String,
+    /// How the batter was out, or `None` if not out
+    pub dismissal: Option<String>,
+    pub runs: i32,
+    pub balls: i32,
+    pub fours: i32,
+    pub sixes: i32,
+    pub strike_rate: Option<f64>,
+}
+
+/// A bowler's line on the scorecard
+#[derive(Debug, Clone, Serialize)]
+pub struct BowlingLine {
+    pub name: String,
+    /// Overs bowled, e.g. "3.2"
+    pub overs: String,
+    pub maidens: i32,
+    pub runs: i32,
+    pub wickets: i32,
+    pub economy: Option<f64>,
+}
+
+/// The extras conceded in an innings
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Extras {
+    pub wides: i32,
+    pub no_balls: i32,
+    pub byes: i32,
+    pub leg_byes: i32,
+    pub penalties: i32,
+    pub total: i32,
+}
+
+/// The score when a wicket fell
+#[derive(Debug, Clone, Serialize)]
+pub struct FallOfWicket {
+    /// Which wicket this was, from 1
+    pub wicket: i32,
+    pub runs: i32,
+    pub batter: String,
+    /// When the wicket fell, e.g. "12.3"
+    pub over: String,
+}
+
+impl Scorecard {
+    #[must_use]
+    pub fn new(cricket_match: &Match) -> Scorecard {
+        Scorecard {
+            innings: cricket_match
+                .innings
+                .iter()
+                .map(InningsScorecard::new)
+                .collect(),
+        }
+    }
+}
+
+impl From<RunSources> for Extras {
+    fn from(sources: RunSources) -> Extras {
+        Extras {
+            wides: sources.wides,
+            no_balls: sources.no_balls,
+            byes: sources.byes,
+            leg_byes: sources.leg_byes,
+            penalties: sources.penalties,
+            total: sources.wides
+                + sources.no_balls
+                + sources.byes
+                + sources.leg_byes
+                + sources.penalties,
+        }
+    }
+}
+
+impl InningsScorecard {
+    #[must_use]
+    pub fn new(innings: &Innings) -> InningsScorecard {
+        // Everyone who has batted, including a batter at the crease yet to face
+        let at_crease = !innings.forfeited && innings.score.wickets_left > 0;
+        let batted = |index: usize, name: &str| {
+            (at_crease && (index == innings.on_strike || index == innings.off_strike))
+                || innings.ball_records.iter().any(|record| {
+                    record.outcome.on_strike.name == name || record.outcome.off_strike.name == name
+                })
+        };
+        let batting = innings
+            .batting_team
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(index, batter)| {
+                batter.out || batter.balls_faced != 0 || batted(*index, &batter.name)
+            })
+            .map(|(_, batter)| BattingLine {
+                name: batter.name.clone(),
+                dismissal: batter.dismissal.clone(),
+                runs: batter.runs,
+                balls: batter.balls_faced,
+                fours: batter.fours,
+                sixes: batter.sixes,
+                strike_rate: batter.strike_rate(),
+            })
+            .collect();
+
+        let summaries = innings.over_summaries();
+        let mut bowlers: Vec<&str> = Vec::new();
+        for summary in &summaries {
+            if !bowlers.contains(&summary.bowler.as_str()) {
+                bowlers.push(&summary.bowler);
+            }
+        }
+        let balls_per_over = innings.score.balls_per_over;
+        let bowling = bowlers
+            .into_iter()
+            .filter_map(|name| {
+                innings
+                    .bowling_team
+                    .players
+                    .iter()
+                    .find(|bowler| bowler.name == name)
+            })
+            .map(|bowler| BowlingLine {
+                name: bowler.name.clone(),
+                overs: Over::from_balls(bowler.balls_bowled, balls_per_over).to_string(),
+                maidens: summaries
+                    .iter()
+                    .filter(|summary| {
+                        summary.bowler == bowler.name
+                            && summary.legal_balls >= balls_per_over
+                            && summary.bowler_runs == 0
+                    })
+                    .count() as i32,
+                runs: bowler.runs_conceded,
+                wickets: bowler.wickets_taken,
+                economy: bowler.economy_rate(),
+            })
+            .collect();
+
+        let extras = Extras::from(innings.run_sources());
+
+        let fall_of_wickets = innings
+            .fall_of_wickets_detailed()
+            .into_iter()
+            .map(|entry| FallOfWicket {
+                wicket: entry.wicket as i32,
+                runs: entry.runs,
+                batter: entry.batter,
+                over: entry.over.to_string(),
+            })
+            .collect();
+
+        InningsScorecard {
+            batting_team: innings.batting_team.name.clone(),
+            bowling_team: innings.bowling_team.name.clone(),
+            batting,
+            bowling,
+            extras,
+            total: innings.score.runs,
+            wickets: innings.score.wickets_lost,
+            overs: Over::from_balls(innings.score.legal_balls(), balls_per_over).to_string(),
+            fall_of_wickets,
+            declared: innings.declared,
+            forfeited: innings.forfeited,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::{
+        ball::{BallEvents, BallOutcome, Wicket},
+        player::{Player, Team},
+        r#match::MatchType,
+    };
+
+    fn create_test_team(name: &str) -> Team {
+        Team {
+            name: name.to_string(),
+            players: vec![
+                Player::new(format!("{name}1")),
+                Player::new(format!("{name}2")),
+                Player::new(format!("{name}3")),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_scorecard_json() {
+        let team1 = create_test_team("A");
+        let team2 = create_test_team("B");
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "T20 Match".to_string(),
+            MatchType::T20,
+            team1.clone(),
+            team2.clone(),
+        );
+        let innings = cricket_match.start_innings(team1.clone(), team2.clone());
+        let ball = |runs: i32, events: Vec<BallEvents>, striker: usize, non_striker: usize| {
+            BallOutcome::new(
+                runs,
+                events,
+                team1.players[striker].clone(),
+                team1.players[non_striker].clone(),
+                team2.players[0].clone(),
+            )
+        };
+        innings.score_ball(&ball(4, vec![BallEvents::Four], 0, 1));
+        innings.score_ball(&ball(0, vec![BallEvents::Wide(1)], 0, 1));
+        innings.score_ball(&ball(
+            0,
+            vec![BallEvents::Wicket(vec![Wicket {
+                player_out: "A1".to_string(),
+                kind: "bowled".to_string(),
+                ..Default::default()
+            }])],
+            0,
+            1,
+        ));
+        innings.score_ball(&ball(1, vec![], 2, 1));
+
+        let scorecard = cricket_match.scorecard_json();
+        let card = &scorecard.innings[0];
+        assert_eq!(card.total, 6);
+        assert_eq!(card.wickets, 1);
+        assert_eq!(card.overs, "0.3");
+        assert_eq!(card.extras.total, 1);
+        assert_eq!(card.batting.len(), 3);
+        assert_eq!(card.bowling.len(), 1);
+        assert_eq!(card.bowling[0].overs, "0.3");
+        assert_eq!(card.bowling[0].wickets, 1);
+        assert_eq!(card.fall_of_wickets.len(), 1);
+        assert_eq!(card.fall_of_wickets[0].runs, 5);
+        assert_eq!(card.fall_of_wickets[0].over, "0.2");
+
+        let json = serde_json::to_value(&scorecard).unwrap();
+        let batting = &json["innings"][0]["batting"];
+        assert_eq!(batting[0]["name"], "A1");
+        assert_eq!(batting[0]["runs"], 4);
+        assert_eq!(batting[0]["dismissal"], "bowled");
+        assert_eq!(batting[1]["name"], "A2");
+        assert_eq!(batting[1]["balls"], 0);
+        assert_eq!(batting[2]["runs"], 1);
+    }
+
+    #[test]
+    fn test_scorecard_includes_batters_yet_to_face() {
+        let team1 = create_test_team("A");
+        let team2 = create_test_team("B");
+        let mut innings = Innings::new(team1.clone(), team2.clone());
+        innings.score_ball(&BallOutcome::new(
+            0,
+            vec![BallEvents::Wicket(vec![Wicket {
+                player_out: "A1".to_string(),
+                kind: "bowled".to_string(),
+                ..Default::default()
+            }])],
+            team1.players[0].clone(),
+            team1.players[1].clone(),
+            team2.players[0].clone(),
+        ));
+
+        // The non-striker and the new batter haven't faced a ball
+        let card = InningsScorecard::new(&innings);
+        let names: Vec<&str> = card.batting.iter().map(|line| line.name.as_str()).collect();
+        assert_eq!(names, vec!["A1", "A2", "A3"]);
+
+        let forfeited = Innings {
+            forfeited: true,
+            ..Innings::new(team1, team2)
+        };
+        assert!(InningsScorecard::new(&forfeited).batting.is_empty());
+    }
+}
@@ -0,0 +1,354 @@
+// Module used to gather statistics across several matches
+
+use crate::scoring::r#match::{Match, MatchResult};
+
+/// Collects matches, e.g. a season or tournament, to report statistics across them
+#[derive(Debug, Clone, Default)]
+pub struct Aggregator {
+    pub matches: Vec<Match>,
+}
+
+/// How many matches ended in each kind of result
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResultBreakdown {
+    pub wins_batting_first: u32,
+    pub wins_bowling_first: u32,
+    pub ties: u32,
+    pub draws: u32,
+    pub no_results: u32,
+}
+
+/// The points awarded for each result in a `PointsTable`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointsConfig {
+    pub win: i32,
+    pub loss: i32,
+    pub tie: i32,
+    pub draw: i32,
+    pub no_result: i32,
+    /// If set, a winner whose run rate in the match was at least this multiple of
+    /// the loser's earns a bonus point, e.g. 1.25 in some domestic one day
+    /// competitions
+    pub bonus_run_rate_ratio: Option<f64>,
+}
+
+/// A team's line in a `PointsTable`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PointsRow {
+    pub team: String,
+    pub played: u32,
+    pub won: u32,
+    pub lost: u32,
+    pub tied: u32,
+    pub drawn: u32,
+    pub no_results: u32,
+    pub bonus_points: i32,
+    /// Total points, including bonus points
+    pub points: i32,
+}
+
+/// A league table of points earned across matches under a `PointsConfig`
+#[derive(Debug, Clone)]
+pub struct PointsTable {
+    pub config: PointsConfig,
+    /// One row per team, in the order they first played
+    pub rows: Vec<PointsRow>,
+}
+
+impl Aggregator {
+    #[must_use]
+    pub fn new() -> Aggregator {
+        Aggregator::default()
+    }
+
+    /// Adds a match to the set
+    pub fn add(&mut self, cricket_match: Match) {
+        self.matches.push(cricket_match);
+    }
+
+    /// Counts the results of the matches, splitting wins by whether the winning
+    /// side batted first. Matches without a result yet aren't counted.
+    #[must_use]
+    pub fn result_breakdown(&self) -> ResultBreakdown {
+        let mut breakdown = ResultBreakdown::default();
+        for cricket_match in &self.matches {
+            match &cricket_match.result {
+                Some(MatchResult::Tie { .. }) => breakdown.ties += 1,
+                Some(MatchResult::Draw) => breakdown.draws += 1,
+                Some(MatchResult::NoResult) => breakdown.no_results += 1,
+                Some(MatchResult::Team1Won { .. } | MatchResult::Team2Won { .. }) | None => {}
+            }
+            let Some(winner) = cricket_match.winner() else {
+                continue;
+            };
+            let batted_first = cricket_match
+                .innings
+                .first()
+                .is_some_and(|innings| innings.batting_team.name == winner);
+            if batted_first {
+                breakdown.wins_batting_first += 1;
+            } else {
+                breakdown.wins_bowling_first += 1;
+            }
+        }
+        breakdown
+    }
+
+    /// A points table of the matches under `config`
+    #[must_use]
+    pub fn points_table(&self, config: PointsConfig) -> PointsTable {
+        let mut table = PointsTable::new(config);
+        for cricket_match in &self.matches {
+            table.add_match(cricket_match);
+        }
+        table
+    }
+}
+
+impl Default for PointsConfig {
+    /// Two points for a win and one for a tie, draw or no result, with no bonus
+    fn default() -> Self {
+        PointsConfig {
+            win: 2,
+            loss: 0,
+            tie: 1,
+            draw: 1,
+            no_result: 1,
+            bonus_run_rate_ratio: None,
+        }
+    }
+}
+
+impl PointsTable {
+    #[must_use]
+    pub fn new(config: PointsConfig) -> PointsTable {
+        PointsTable {
+            config,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a match's points to both teams' rows. Matches without a result yet
+    /// aren't counted.
+    pub fn add_match(&mut self, cricket_match: &Match) {
+        let Some(result) = &cricket_match.result else {
+            return;
+        };
+        let config = self.config.clone();
+        let winner = cricket_match.winner().map(str::to_string);
+        let bonus = winner.as_deref().is_some_and(|winner| {
+            config
+                .bonus_run_rate_ratio
+                .is_some_and(|ratio| earns_bonus_point(cricket_match, winner, ratio))
+        });
+        for team in [&cricket_match.team1.name, &cricket_match.team2.name] {
+            let row = self.row_mut(team);
+            row.played += 1;
+            match result {
+                MatchResult::Tie { .. } => {
+                    row.tied += 1;
+                    row.points += config.tie;
+                }
+                MatchResult::Draw => {
+                    row.drawn += 1;
+                    row.points += config.draw;
+                }
+                MatchResult::NoResult => {
+                    row.no_results += 1;
+                    row.points += config.no_result;
+                }
+                MatchResult::Team1Won { .. } | MatchResult::Team2Won { .. } => {
+                    if winner.as_ref() == Some(team) {
+                        row.won += 1;
+                        row.points += config.win;
+                        if bonus {
+                            row.bonus_points += 1;
+                            row.points += 1;
+                        }
+                    } else {
+                        row.lost += 1;
+                        row.points += config.loss;
+                    }
+                }
+            }
+        }
+    }
+
+    /// The row for `team`, if it has played
+    #[must_use]
+    pub fn row(&self, team: &str) -> Option<&PointsRow> {
+        self.rows.iter().find(|row| row.team == team)
+    }
+
+    /// The rows ordered by points, highest first, keeping teams level on points in
+    /// the order they first played
+    #[must_use]
+    pub fn standings(&self) -> Vec<&PointsRow> {
+        let mut standings: Vec<&PointsRow> = self.rows.iter().collect();
+        standings.sort_by_key(|row| std::cmp::Reverse(row.points));
+        standings
+    }
+
+    fn row_mut(&mut self, team: &str) -> &mut PointsRow {
+        let index = match self.rows.iter().position(|row| row.team == team) {
+            Some(index) => index,
+            None => {
+                self.rows.push(PointsRow {
+                    team: team.to_string(),
+                    ..Default::default()
+                });
+                self.rows.len() - 1
+            }
+        };
+        &mut self.rows[index]
+    }
+}
+
+/// Whether `winner`'s run rate across the match was at least `ratio` times the
+/// other side's. Run rates are runs per legal ball over all of a side's innings,
+/// leaving out super overs.
+fn earns_bonus_point(cricket_match: &Match, winner: &str, ratio: f64) -> bool {
+    let run_rate = |winning_side: bool| {
+        let (runs, balls) = cricket_match
+            .innings
+            .iter()
+            .filter(|innings| (innings.batting_team.name == winner) == winning_side)
+            .fold((0, 0), |(runs, balls), innings| {
+                (
+                    runs + innings.score.runs,
+                    balls + innings.score.legal_balls(),
+                )
+            });
+        (balls > 0).then(|| f64::from(runs) / f64::from(balls))
+    };
+    match (run_rate(true), run_rate(false)) {
+        (Some(winner_rate), Some(loser_rate)) => winner_rate >= loser_rate * ratio,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::{
+        innings::Innings,
+        player::{Player, Team},
+        r#match::MatchType,
+    };
+
+    fn create_test_team(name: &str) -> Team {
+        Team {
+            name: name.to_string(),
+            players: vec![
+                Player::new("Player1".to_string()),
+                Player::new("Player2".to_string()),
+            ],
+            ..Default::default()
+        }
+    }
+
+    /// A completed two innings match with the given first and second innings totals
+    fn create_test_match(first: &Team, second: &Team, runs: (i32, i32)) -> Match {
+        let mut cricket_match = Match::new(
+            "M001".to_string(),
+            "Test Match".to_string(),
+            MatchType::T20,
+            create_test_team("Team A"),
+            create_test_team("Team B"),
+        );
+        let mut innings1 = Innings::new(first.clone(), second.clone());
+        innings1.score.runs = runs.0;
+        cricket_match.add_innings(innings1);
+        let mut innings2 = Innings::new(second.clone(), first.clone());
+        innings2.score.runs = runs.1;
+        innings2.score.wickets_left = if runs.1 > runs.0 { 5 } else { 0 };
+        cricket_match.add_innings(innings2);
+        cricket_match.calculate_result();
+        cricket_match
+    }
+
+    #[test]
+    fn test_result_breakdown() {
+        let team_a = create_test_team("Team A");
+        let team_b = create_test_team("Team B");
+
+        let mut aggregator = Aggregator::new();
+        aggregator.add(create_test_match(&team_a, &team_b, (180, 150)));
+        aggregator.add(create_test_match(&team_b, &team_a, (160, 120)));
+        aggregator.add(create_test_match(&team_a, &team_b, (140, 141)));
+        aggregator.add(create_test_match(&team_a, &team_b, (150, 150)));
+
+        let mut abandoned = create_test_match(&team_a, &team_b, (0, 0));
+        abandoned.set_result(MatchResult::NoResult);
+        aggregator.add(abandoned);
+
+        // Not yet finished, so not counted
+        aggregator.add(Match::new(
+            "M002".to_string(),
+            "Upcoming".to_string(),
+            MatchType::T20,
+            team_a,
+            team_b,
+        ));
+
+        assert_eq!(
+            aggregator.result_breakdown(),
+            ResultBreakdown {
+                wins_batting_first: 2,
+                wins_bowling_first: 1,
+                ties: 1,
+                draws: 0,
+                no_results: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_points_table_bonus_point() {
+        let team_a = create_test_team("Team A");
+        let team_b = create_test_team("Team B");
+        let twenty_overs = |mut cricket_match: Match| {
+            for innings in &mut cricket_match.innings {
+                innings.score.over = 20;
+            }
+            cricket_match
+        };
+
+        let mut aggregator = Aggregator::new();
+        // Team A win big and Team B narrowly, then a tie
+        aggregator.add(twenty_overs(create_test_match(
+            &team_a,
+            &team_b,
+            (200, 120),
+        )));
+        aggregator.add(twenty_overs(create_test_match(
+            &team_b,
+            &team_a,
+            (150, 140),
+        )));
+        aggregator.add(twenty_overs(create_test_match(
+            &team_a,
+            &team_b,
+            (150, 150),
+        )));
+
+        let table = aggregator.points_table(PointsConfig {
+            bonus_run_rate_ratio: Some(1.25),
+            ..Default::default()
+        });
+        let row_a = table.row("Team A").unwrap();
+        assert_eq!(
+            (row_a.played, row_a.won, row_a.lost, row_a.tied),
+            (3, 1, 1, 1)
+        );
+        assert_eq!(row_a.bonus_points, 1);
+        assert_eq!(row_a.points, 4);
+        let row_b = table.row("Team B").unwrap();
+        assert_eq!(row_b.bonus_points, 0);
+        assert_eq!(row_b.points, 3);
+        assert_eq!(table.standings()[0].team, "Team A");
+
+        let table = aggregator.points_table(PointsConfig::default());
+        assert_eq!(table.row("Team A").unwrap().points, 3);
+    }
+}
@@ -9,10 +9,12 @@ fn test_match_creation_and_result_setting() {
     let team1 = Team {
         name: "Team A".to_string(),
         players: vec![Player::new("Player1".to_string())],
+        ..Default::default()
     };
     let team2 = Team {
         name: "Team B".to_string(),
         players: vec![Player::new("Player2".to_string())],
+        ..Default::default()
     };
 
     let mut cricket_match = Match::new(
@@ -54,10 +56,12 @@ fn test_calculate_result_functionality() {
     let team1 = Team {
         name: "Team A".to_string(),
         players: vec![Player::new("Player1".to_string())],
+        ..Default::default()
     };
     let team2 = Team {
         name: "Team B".to_string(),
         players: vec![Player::new("Player2".to_string())],
+        ..Default::default()
     };
 
     let mut cricket_match = Match::new(
@@ -245,3 +249,359 @@ fn test_cricsheet_batter_scoring_regression() {
     // Verify total is correct
     assert_eq!(innings.score.runs, 2, "Total should be 2 runs");
 }
+
+#[cfg(feature = "cricsheet")]
+const SAMPLE_CRICSHEET: &str = r#"{
+    "meta": { "data_version": "1.1.0", "created": "2024-01-01", "revision": 1 },
+    "info": {
+        "balls_per_over": 6,
+        "dates": ["2024-01-01"],
+        "event": { "name": "Sample Cup", "group": 2 },
+        "gender": "male",
+        "match_type": "T20",
+        "match_type_number": 1234,
+        "outcome": { "winner": "Team A", "by": { "runs": 1 } },
+        "overs": 20,
+        "players": { "Team A": ["A1", "A2"], "Team B": ["B1", "B2"] },
+        "registry": { "people": {} },
+        "season": 2024,
+        "team_type": "club",
+        "teams": ["Team A", "Team B"],
+        "toss": { "decision": "bat", "winner": "Team A" }
+    },
+    "innings": [
+        {
+            "team": "Team A",
+            "overs": [
+                {
+                    "over": 0,
+                    "deliveries": [
+                        {
+                            "batter": "A1",
+                            "bowler": "B1",
+                            "non_striker": "A2",
+                            "runs": { "batter": 1, "extras": 0, "total": 1 }
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_from_json() {
+    let cricsheet = cricket_scoring::cricsheet::from_json(SAMPLE_CRICSHEET).unwrap();
+
+    // Numeric seasons and groups are read as strings
+    assert_eq!(cricsheet.info.season, "2024");
+    assert_eq!(
+        cricsheet.info.event.as_ref().unwrap().group,
+        Some("2".to_string())
+    );
+    assert_eq!(cricsheet.innings.len(), 1);
+
+    assert!(cricket_scoring::cricsheet::from_json("{}").is_err());
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_season_and_match_number() {
+    let cricsheet = cricket_scoring::cricsheet::from_json(SAMPLE_CRICSHEET).unwrap();
+    let cricket_match = cricsheet.create_game();
+
+    assert_eq!(cricket_match.season, Some("2024".to_string()));
+    assert_eq!(cricket_match.match_number, Some(1234));
+    assert_eq!(
+        cricket_match.ordering_key(),
+        (Some("2024".to_string()), Some("2024-01-01".to_string()))
+    );
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_dates() {
+    let json = SAMPLE_CRICSHEET.replace(
+        r#""dates": ["2024-01-01"]"#,
+        r#""dates": ["2024-01-01", "2024-01-02", "2024-01-03"]"#,
+    );
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let cricket_match = cricsheet.create_game();
+
+    assert_eq!(cricket_match.dates.len(), 3);
+    assert!(cricket_match.is_multi_day());
+    assert_eq!(cricket_match.first_date(), Some(&"2024-01-01".to_string()));
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_target_and_powerplay_overs() {
+    use cricket_scoring::Over;
+
+    let json = SAMPLE_CRICSHEET.replace(
+        r#""team": "Team A","#,
+        r#""team": "Team A",
+            "target": { "overs": 19.3, "runs": 150 },
+            "powerplays": [{ "from": 0.1, "to": 5.6, "type": "mandatory" }],"#,
+    );
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let innings = &cricsheet.innings[0];
+
+    let target = innings.target.as_ref().unwrap();
+    assert_eq!(target.overs, Some(Over::new(19, 3)));
+    assert_eq!(target.balls(6), Some(117));
+
+    let powerplay = &innings.powerplays.as_ref().unwrap()[0];
+    assert_eq!(powerplay.from, Over::new(0, 0));
+    assert_eq!(powerplay.to, Over::new(5, 5));
+    assert_eq!(
+        powerplay.to.to_balls(6) - powerplay.from.to_balls(6) + 1,
+        36
+    );
+    let json = serde_json::to_value(powerplay).unwrap();
+    assert_eq!(json["from"], 0.1);
+    assert_eq!(json["to"], 5.6);
+
+    let mut cricket_match = cricsheet.create_game();
+    innings.process_innings(&mut cricket_match);
+    assert_eq!(cricket_match.innings[0].target, Some(150));
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_supersubs() {
+    let json = SAMPLE_CRICSHEET.replace(
+        r#""players": { "Team A": ["A1", "A2"], "Team B": ["B1", "B2"] },"#,
+        r#""players": { "Team A": ["A1", "A2", "A3", "A4"], "Team B": ["B1", "B2"] },
+        "supersubs": { "Team A": "A3", "Team B": "B3" },"#,
+    );
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let cricket_match = cricsheet.create_game();
+
+    let team_a = &cricket_match.team1;
+    assert!(team_a.players[2].super_sub);
+    assert_eq!(team_a.next_batter_index(1), 3);
+
+    // A super-sub missing from the squad list is still recorded
+    let team_b = &cricket_match.team2;
+    assert_eq!(team_b.players.len(), 3);
+    assert_eq!(team_b.players[2].name, "B3");
+    assert!(team_b.players[2].super_sub);
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_awarded_match() {
+    let json = SAMPLE_CRICSHEET.replace(
+        r#""outcome": { "winner": "Team A", "by": { "runs": 1 } }"#,
+        r#""outcome": { "winner": "Team A", "method": "forfeit" }"#,
+    );
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let cricket_match = cricsheet.create_game();
+
+    assert!(cricsheet.info.outcome.is_awarded());
+    assert!(matches!(
+        cricket_match.result,
+        Some(MatchResult::Team1Won {
+            margin: WinMargin::Award,
+            method: Some(cricket_scoring::ResultMethod::Forfeit),
+        })
+    ));
+    assert_eq!(
+        cricket_match.result_summary(),
+        "Team A awarded the match (forfeit)"
+    );
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_innings_format() {
+    let json = SAMPLE_CRICSHEET
+        .replace(r#""match_type": "T20""#, r#""match_type": "T10""#)
+        .replace(r#""overs": 20"#, r#""overs": 10"#)
+        .replace(r#""balls_per_over": 6"#, r#""balls_per_over": 5"#);
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let mut cricket_match = cricsheet.create_game();
+    for innings_data in &cricsheet.innings {
+        innings_data.process_innings(&mut cricket_match);
+    }
+
+    let innings = &cricket_match.innings[0];
+    assert_eq!(innings.max_overs, Some(10));
+    assert_eq!(innings.score.balls_per_over, 5);
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_delivery_names_are_normalized() {
+    let json = SAMPLE_CRICSHEET
+        .replace(r#""batter": "A1""#, r#""batter": "a1 ""#)
+        .replace(r#""bowler": "B1""#, r#""bowler": "B1  ""#);
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let mut cricket_match = cricsheet.create_game();
+    cricsheet.innings[0].process_innings(&mut cricket_match);
+
+    let innings = &cricket_match.innings[0];
+    assert_eq!(innings.batting_team.players[0].runs, 1);
+    assert_eq!(
+        cricket_scoring::cricsheet::normalize_name("cj bowes"),
+        cricket_scoring::cricsheet::normalize_name("CJ  Bowes")
+    );
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_delivery_review() {
+    let json = SAMPLE_CRICSHEET.replace(
+        r#""runs": { "batter": 1, "extras": 0, "total": 1 }"#,
+        r#""runs": { "batter": 1, "extras": 0, "total": 1 },
+                            "review": { "batter": "A1", "by": "Team B", "decision": "struck down", "umpire": "U1" }"#,
+    );
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let mut cricket_match = cricsheet.create_game();
+    cricsheet.innings[0].process_innings(&mut cricket_match);
+
+    let innings = &cricket_match.innings[0];
+    let reviews = innings.reviews();
+    assert_eq!(reviews.len(), 1);
+    assert_eq!(reviews[0].by, "Team B");
+    assert_eq!(reviews[0].batter, "A1");
+    assert_eq!(reviews[0].umpire, Some("U1".to_string()));
+    assert!(!reviews[0].upheld);
+    assert_eq!(innings.bowling_team.reviews_used, 1);
+    assert_eq!(innings.bowling_team.reviews_successful, 0);
+    assert_eq!(innings.batting_team.reviews_used, 0);
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_result_method_is_typed() {
+    let json = SAMPLE_CRICSHEET.replace(
+        r#""outcome": { "winner": "Team A", "by": { "runs": 1 } }"#,
+        r#""outcome": { "winner": "Team A", "by": { "runs": 12 }, "method": "D/L" }"#,
+    );
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let result = cricsheet
+        .info
+        .outcome
+        .create_match_result("Team A", "Team B");
+
+    assert!(matches!(
+        result,
+        MatchResult::Team1Won {
+            margin: WinMargin::Runs(12),
+            method: Some(cricket_scoring::ResultMethod::DuckworthLewis),
+        }
+    ));
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_gender_and_team_type() {
+    let json = SAMPLE_CRICSHEET.replace(r#""gender": "male""#, r#""gender": "female""#);
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let cricket_match = cricsheet.create_game();
+
+    assert_eq!(cricket_match.gender, Some("female".to_string()));
+    assert_eq!(cricket_match.team_type, Some("club".to_string()));
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_innings_round_trip() {
+    let json = SAMPLE_CRICSHEET.replace(
+        r#""runs": { "batter": 1, "extras": 0, "total": 1 }"#,
+        r#""runs": { "batter": 1, "extras": 0, "total": 1 }
+                        },
+                        {
+                            "batter": "A2",
+                            "bowler": "B1",
+                            "extras": { "wides": 3 },
+                            "non_striker": "A1",
+                            "runs": { "batter": 0, "extras": 3, "total": 3 }"#,
+    );
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let mut cricket_match = cricsheet.create_game();
+    cricsheet.innings[0].process_innings(&mut cricket_match);
+
+    let exported = cricket_match.innings[0].to_cricsheet_innings();
+    let reparsed: cricket_scoring::cricsheet::CricsheetInnings =
+        serde_json::from_str(&serde_json::to_string(&exported).unwrap()).unwrap();
+
+    let original = cricsheet.innings[0].overs.as_ref().unwrap();
+    let overs = reparsed.overs.as_ref().unwrap();
+    assert_eq!(reparsed.team, "Team A");
+    assert_eq!(overs.len(), original.len());
+    assert_eq!(overs[0].deliveries.len(), original[0].deliveries.len());
+    let first = &overs[0].deliveries[0];
+    assert_eq!(first.batter, "A1");
+    assert_eq!(first.runs.batter, original[0].deliveries[0].runs.batter);
+    assert_eq!(first.runs.total, original[0].deliveries[0].runs.total);
+    assert_eq!(
+        overs[0].deliveries[1].extras.as_ref().unwrap().wides,
+        Some(3)
+    );
+    assert_eq!(overs[0].deliveries[1].runs.total, 3);
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_delivery_boundary_overthrows() {
+    use cricket_scoring::{cricsheet::Delivery, BallEvents, BallOutcome};
+
+    // Ran 2, then the throw went for four overthrows
+    let outcome = BallOutcome::new(
+        2,
+        vec![BallEvents::BoundaryOverthrows(4)],
+        Player::new("A1".to_string()),
+        Player::new("A2".to_string()),
+        Player::new("B1".to_string()),
+    );
+    let delivery = Delivery::from(&outcome);
+    assert_eq!(delivery.runs.batter, 6);
+    assert_eq!(delivery.runs.non_boundary, Some(true));
+
+    let six = BallOutcome::new(
+        6,
+        vec![BallEvents::Six],
+        Player::new("A1".to_string()),
+        Player::new("A2".to_string()),
+        Player::new("B1".to_string()),
+    );
+    assert_eq!(Delivery::from(&six).runs.non_boundary, None);
+}
+
+#[cfg(feature = "cricsheet")]
+#[test]
+fn test_cricsheet_innings_penalty_runs() {
+    let json = SAMPLE_CRICSHEET
+        .replace(
+            r#""team": "Team A","#,
+            r#""team": "Team A",
+            "penalty_runs": { "pre": 5 },"#,
+        )
+        .replace(
+            r#""runs": { "batter": 1, "extras": 0, "total": 1 }"#,
+            r#""extras": { "penalty": 5 },
+                            "runs": { "batter": 1, "extras": 5, "total": 6 }"#,
+        );
+    let cricsheet = cricket_scoring::cricsheet::from_json(&json).unwrap();
+    let mut cricket_match = cricsheet.create_game();
+    cricsheet.innings[0].process_innings(&mut cricket_match);
+
+    let innings = &cricket_match.innings[0];
+    assert_eq!(innings.score.runs, 11);
+    assert_eq!(innings.run_sources().penalties, 10);
+    assert_eq!(innings.run_sources().off_bat, 1);
+    assert_eq!(innings.score.innings_penalties, 5);
+    assert_eq!(innings.state_after_ball(0).unwrap().score.runs, 5);
+    assert_eq!(innings.state_after_ball(1).unwrap().score.runs, 11);
+    let bowler = innings
+        .bowling_team
+        .players
+        .iter()
+        .find(|p| p.name == "B1")
+        .unwrap();
+    assert_eq!(bowler.runs_conceded, 1);
+}
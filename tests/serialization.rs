@@ -1,5 +1,4 @@
 use cricket_scoring::*;
-use serde_json;
 
 #[test]
 fn test_player_serialization() {
@@ -36,6 +35,7 @@ fn test_team_serialization() {
             Player::new("Player1".to_string()),
             Player::new("Player2".to_string()),
         ],
+        ..Default::default()
     };
 
     let json = serde_json::to_string(&team).unwrap();
@@ -73,10 +73,12 @@ fn test_match_serialization() {
     let team1 = Team {
         name: "Team A".to_string(),
         players: vec![Player::new("Player1".to_string())],
+        ..Default::default()
     };
     let team2 = Team {
         name: "Team B".to_string(),
         players: vec![Player::new("Player2".to_string())],
+        ..Default::default()
     };
 
     let match_instance = Match::new(
@@ -95,12 +97,46 @@ fn test_match_serialization() {
     assert_eq!(deserialized.id, "M001");
     assert_eq!(deserialized.title, "Test Match");
     assert_eq!(deserialized.venue, Some("Test Ground".to_string()));
-    assert_eq!(deserialized.date, Some("2025-01-01".to_string()));
+    assert_eq!(deserialized.dates, vec!["2025-01-01".to_string()]);
     assert!(matches!(deserialized.match_type, MatchType::T20));
     assert_eq!(deserialized.team1.name, "Team A");
     assert_eq!(deserialized.team2.name, "Team B");
 }
 
+#[test]
+fn test_match_deserializes_single_date() {
+    let team = |name: &str| Team {
+        name: name.to_string(),
+        players: vec![Player::new("Player1".to_string())],
+        ..Default::default()
+    };
+    let match_instance = Match::new(
+        "M001".to_string(),
+        "Test Match".to_string(),
+        MatchType::T20,
+        team("Team A"),
+        team("Team B"),
+    );
+
+    // Older files have a single, optional date rather than a list
+    let mut old = serde_json::to_value(&match_instance).unwrap();
+    let fields = old.as_object_mut().unwrap();
+    fields.remove("dates");
+    fields.insert("date".to_string(), serde_json::json!("2025-01-01"));
+
+    let deserialized: Match = serde_json::from_value(old.clone()).unwrap();
+    assert_eq!(deserialized.dates, vec!["2025-01-01".to_string()]);
+
+    let json = serde_json::to_string(&deserialized).unwrap();
+    assert!(json.contains(r#""dates":["2025-01-01"]"#));
+    let round_tripped: Match = serde_json::from_str(&json).unwrap();
+    assert_eq!(round_tripped.dates, deserialized.dates);
+
+    old["date"] = serde_json::Value::Null;
+    let deserialized: Match = serde_json::from_value(old).unwrap();
+    assert!(deserialized.dates.is_empty());
+}
+
 #[test]
 fn test_match_result_serialization() {
     let results = vec![
@@ -114,7 +150,7 @@ fn test_match_result_serialization() {
         },
         MatchResult::Team1Won {
             margin: WinMargin::Award,
-            method: Some("forfeit".to_string()),
+            method: Some(ResultMethod::Forfeit),
         },
         MatchResult::Tie { method: None },
         MatchResult::Draw,
@@ -192,6 +228,7 @@ fn test_wicket_serialization() {
     let wicket = Wicket {
         player_out: "Test Player".to_string(),
         kind: "bowled".to_string(),
+        ..Default::default()
     };
 
     let json = serde_json::to_string(&wicket).unwrap();